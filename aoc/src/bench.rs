@@ -0,0 +1,135 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use common::{Error, Timings};
+use serde_json::json;
+
+use crate::days::Timer;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Text,
+    Markdown,
+    Json,
+}
+
+pub struct Stats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn of(samples: &[Duration]) -> Stats {
+        Stats {
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+            min: samples.iter().copied().min().unwrap_or_default(),
+            max: samples.iter().copied().max().unwrap_or_default(),
+        }
+    }
+}
+
+/// Timings of a day over all the runs.
+pub struct Row {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Times `runs` runs of a day, after an untimed one to warm up.
+pub fn measure(day: u8, time: Timer, input: &str, runs: u32) -> Result<Row, Error> {
+    time(input)?;
+    let samples = (0..runs)
+        .map(|_| time(input))
+        .collect::<Result<Vec<Timings>, Error>>()?;
+    let stats =
+        |step: fn(&Timings) -> Duration| Stats::of(&samples.iter().map(step).collect::<Vec<_>>());
+    Ok(Row {
+        day,
+        parse: stats(|t| t.parse),
+        part1: stats(|t| t.part1),
+        part2: stats(|t| t.part2),
+    })
+}
+
+impl Row {
+    fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ]
+    }
+}
+
+pub fn report(rows: &[Row], runs: u32, format: Format) -> String {
+    match format {
+        Format::Text => text(rows),
+        Format::Markdown => markdown(rows, runs),
+        Format::Json => json(rows, runs),
+    }
+}
+
+fn text(rows: &[Row]) -> String {
+    let mut out = format!(
+        "{:<4} {:<7} {:>12} {:>12} {:>12}\n",
+        "day", "step", "mean", "min", "max"
+    );
+    for row in rows {
+        for (step, stats) in row.steps() {
+            let _ = writeln!(
+                out,
+                "{:<4} {:<7} {:>12} {:>12} {:>12}",
+                row.day,
+                step,
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.max)
+            );
+        }
+    }
+    out
+}
+
+fn markdown(rows: &[Row], runs: u32) -> String {
+    let mut out = format!("Timings over {} runs.\n\n", runs);
+    out.push_str("| Day | Step | Mean | Min | Max |\n");
+    out.push_str("|----:|------|-----:|----:|----:|\n");
+    for row in rows {
+        for (step, stats) in row.steps() {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {:.2?} | {:.2?} | {:.2?} |",
+                row.day, step, stats.mean, stats.min, stats.max
+            );
+        }
+    }
+    out
+}
+
+fn json(rows: &[Row], runs: u32) -> String {
+    let stats = |stats: &Stats| {
+        json!({
+            "mean_ns": stats.mean.as_nanos() as u64,
+            "min_ns": stats.min.as_nanos() as u64,
+            "max_ns": stats.max.as_nanos() as u64,
+        })
+    };
+    let days = rows
+        .iter()
+        .map(|row| {
+            json!({
+                "day": row.day,
+                "parse": stats(&row.parse),
+                "part1": stats(&row.part1),
+                "part2": stats(&row.part2),
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut out = serde_json::to_string_pretty(&json!({ "runs": runs, "days": days }))
+        .expect("timings serialize to JSON");
+    out.push('\n');
+    out
+}
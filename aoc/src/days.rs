@@ -0,0 +1,47 @@
+use common::{Error, Solved, Timings};
+
+/// Solves one part of a day, or both when the part is `None`.
+pub type Runner = fn(&str, Option<u8>) -> Result<Vec<Solved>, Error>;
+
+/// Solves both parts of a day, timing each step.
+pub type Timer = fn(&str) -> Result<Timings, Error>;
+
+pub struct Day {
+    pub run: Runner,
+    pub time: Timer,
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            run: common::run::<$solution>,
+            time: common::time::<$solution>,
+        }
+    };
+}
+
+// Solvers indexed by day, `DAYS[0]` is dec-1.
+pub const DAYS: &[Day] = &[
+    day!(dec_1::Day1),
+    day!(dec_2::Day2),
+    day!(dec_3::Day3),
+    day!(dec_4::Day4),
+    day!(dec_5::Day5),
+    day!(dec_6::Day6),
+    day!(dec_7::Day7),
+    day!(dec_8::Day8),
+    day!(dec_9::Day9),
+    day!(dec_10::Day10),
+    day!(dec_11::Day11),
+    day!(dec_12::Day12),
+    day!(dec_13::Day13),
+    day!(dec_14::Day14),
+    day!(dec_15::Day15),
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    if day == 0 {
+        return None;
+    }
+    DAYS.get(day as usize - 1)
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+/// Options choosing the input of a day.
+#[derive(Args)]
+pub struct InputArgs {
+    /// Puzzle input file, `-` reads stdin. Defaults to dec-<day>/input.txt
+    #[arg(long, conflicts_with = "example")]
+    pub input: Option<String>,
+    /// Use the bundled example, input-test.txt or input-test<N>.txt for N > 1
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1",
+          value_parser = clap::value_parser!(u8).range(1..))]
+    pub example: Option<u8>,
+}
+
+impl InputArgs {
+    pub fn source(&self, day: u8) -> Source {
+        match &self.input {
+            Some(arg) => Source::from_arg(arg),
+            None => Source::bundled(day, self.example),
+        }
+    }
+}
+
+/// Where the puzzle input of a run comes from.
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` reads stdin, anything else is a path relative to the working directory.
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Input bundled with a day: `input.txt`, or `input-test.txt` / `input-test<n>.txt`
+    /// for example `n`. Found from the workspace, whatever the working directory.
+    pub fn bundled(day: u8, example: Option<u8>) -> Source {
+        let name = match example {
+            None => "input.txt".to_string(),
+            Some(1) => "input-test.txt".to_string(),
+            Some(n) => format!("input-test{}.txt", n),
+        };
+        Source::File(workspace_root().join(format!("dec-{}", day)).join(name))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        }
+    }
+
+    /// Reads the input as it comes, for inputs too big to be read at once.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc lives inside the workspace")
+}
@@ -0,0 +1,54 @@
+use clap::ValueEnum;
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// How much the solvers tell about their work, on stderr.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Verbosity {
+    /// Only warnings, the answers print alone
+    Quiet,
+    /// Intermediate results
+    Info,
+    /// Progress of the solvers
+    Debug,
+    /// Every step of the solvers
+    Trace,
+}
+
+impl Verbosity {
+    fn filter(self) -> LevelFilter {
+        match self {
+            Verbosity::Quiet => LevelFilter::Warn,
+            Verbosity::Info => LevelFilter::Info,
+            Verbosity::Debug => LevelFilter::Debug,
+            Verbosity::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+struct Stderr;
+
+impl Log for Stderr {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{:<5} {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Stderr = Stderr;
+
+pub fn init(verbosity: Verbosity) {
+    log::set_logger(&LOGGER).expect("the logger is only set once");
+    log::set_max_level(verbosity.filter());
+}
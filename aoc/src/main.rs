@@ -0,0 +1,189 @@
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+mod bench;
+mod days;
+mod input;
+mod logger;
+mod output;
+mod scaffold;
+mod tools;
+
+use input::{InputArgs, Source};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 puzzle runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How much the solvers log on stderr
+    #[arg(long, global = true, value_enum, default_value_t = logger::Verbosity::Quiet)]
+    verbosity: logger::Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of some days
+    Run {
+        /// Days of the puzzles, as in dec-<day>, all of them by default
+        days: Vec<u8>,
+        /// Only run this part, both parts run otherwise
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// How the answers are printed
+        #[arg(long, value_enum, default_value_t = output::Format::Text)]
+        format: output::Format,
+    },
+    /// Time parsing and both parts of some days
+    Bench {
+        /// Days to time, all of them by default
+        days: Vec<u8>,
+        /// Number of timed runs of each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Use the bundled examples, input-test.txt or input-test<N>.txt for N > 1
+        #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1",
+              value_parser = clap::value_parser!(u8).range(1..))]
+        example: Option<u8>,
+        /// Format of the report
+        #[arg(long, value_enum, default_value_t = bench::Format::Text)]
+        format: bench::Format,
+        /// Write the report to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Create the crate of the next day and register it with the runner
+    New {
+        /// Day of the puzzle, the one after the last solved day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    #[command(flatten)]
+    Tool(tools::Tool),
+}
+
+fn read(source: &Source) -> Option<String> {
+    match source.read() {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", source, err);
+            None
+        }
+    }
+}
+
+fn run(days: Vec<u8>, part: Option<u8>, input: InputArgs, format: output::Format) -> ExitCode {
+    let days = if days.is_empty() {
+        (1..=days::DAYS.len() as u8).collect()
+    } else {
+        days
+    };
+    if input.input.is_some() && days.len() != 1 {
+        eprintln!("--input needs a single day");
+        return ExitCode::FAILURE;
+    }
+    let mut runs = vec![];
+    for day in days {
+        let Some(solver) = days::get(day) else {
+            eprintln!("No solver for day {}", day);
+            return ExitCode::FAILURE;
+        };
+        let source = input.source(day);
+        let Some(text) = read(&source) else {
+            return ExitCode::FAILURE;
+        };
+        match (solver.run)(&text, part) {
+            Ok(solved) => runs.push(output::DayRun {
+                day,
+                input: source.to_string(),
+                solved,
+            }),
+            Err(err) => {
+                eprintln!("{}", err.in_file(source.to_string()));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    print!("{}", output::render(&runs, format));
+    ExitCode::SUCCESS
+}
+
+fn bench(
+    days: Vec<u8>,
+    runs: u32,
+    example: Option<u8>,
+    format: bench::Format,
+    output: Option<PathBuf>,
+) -> ExitCode {
+    let days = if days.is_empty() {
+        (1..=days::DAYS.len() as u8).collect()
+    } else {
+        days
+    };
+    let mut rows = vec![];
+    for day in days {
+        let Some(solver) = days::get(day) else {
+            eprintln!("No solver for day {}", day);
+            return ExitCode::FAILURE;
+        };
+        let source = Source::bundled(day, example);
+        let Some(input) = read(&source) else {
+            return ExitCode::FAILURE;
+        };
+        match bench::measure(day, solver.time, &input, runs) {
+            Ok(row) => rows.push(row),
+            Err(err) => {
+                eprintln!("{}", err.in_file(source.to_string()));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    let report = bench::report(&rows, runs, format);
+    match output {
+        Some(path) => {
+            if let Err(err) = fs::write(&path, report) {
+                eprintln!("Cannot write {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", report),
+    }
+    ExitCode::SUCCESS
+}
+
+fn new(day: u8) -> ExitCode {
+    if let Err(err) = scaffold::new_day(input::workspace_root(), day) {
+        eprintln!("Cannot create day {}: {}", day, err);
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "Created dec-{day}, paste the puzzle into dec-{day}/src/lib.rs and the inputs into dec-{day}/input.txt and dec-{day}/input-test.txt"
+    );
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logger::init(cli.verbosity);
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+        } => run(days, part, input, format),
+        Command::Bench {
+            days,
+            runs,
+            example,
+            format,
+            output,
+        } => bench(days, runs, example, format, output),
+        Command::New { day } => new(day),
+        Command::Tool(tool) => tools::run(tool),
+    }
+}
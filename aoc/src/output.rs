@@ -0,0 +1,106 @@
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use common::{Answer, Solved};
+use serde_json::{json, Value};
+
+/// How `aoc run` prints the answers.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// A `Day <day>, <input>` line per day, then one `part <part> in
+    /// <elapsed>: <answer>` line per part
+    Text,
+    /// An array of `{day, part, answer, input, elapsed_ns}` objects
+    Json,
+    /// A `day part answer input elapsed_ns` header then one row per part
+    Tsv,
+}
+
+/// The answers of a day, with the input they were found from.
+pub struct DayRun {
+    pub day: u8,
+    pub input: String,
+    pub solved: Vec<Solved>,
+}
+
+pub fn render(runs: &[DayRun], format: Format) -> String {
+    match format {
+        Format::Text => text(runs),
+        Format::Json => json(runs),
+        Format::Tsv => tsv(runs),
+    }
+}
+
+fn text(runs: &[DayRun]) -> String {
+    let mut out = String::new();
+    for (i, run) in runs.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(out, "Day {}, {}", run.day, run.input);
+        for s in &run.solved {
+            let _ = writeln!(out, "part {} in {:.2?}: {}", s.part, s.elapsed, s.answer);
+        }
+    }
+    out
+}
+
+// Numbers stay numbers, a part without answer is `null`.
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Num(n) => json!(n),
+        Answer::Text(s) => json!(s),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+fn json(runs: &[DayRun]) -> String {
+    let rows = runs
+        .iter()
+        .flat_map(|run| {
+            run.solved.iter().map(|s| {
+                json!({
+                    "day": run.day,
+                    "part": s.part,
+                    "answer": answer_value(&s.answer),
+                    "input": run.input,
+                    "elapsed_ns": s.elapsed.as_nanos() as u64,
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut out = serde_json::to_string(&rows).expect("answers serialize to JSON");
+    out.push('\n');
+    out
+}
+
+// Tabs and newlines, like the lines of the dec-10 CRT, are escaped to keep one row per part.
+fn tsv_field(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn tsv(runs: &[DayRun]) -> String {
+    let mut out = String::from("day\tpart\tanswer\tinput\telapsed_ns\n");
+    for (run, s) in runs
+        .iter()
+        .flat_map(|run| run.solved.iter().map(move |s| (run, s)))
+    {
+        let answer = match &s.answer {
+            Answer::Unsolved => String::new(),
+            answer => tsv_field(&answer.to_string()),
+        };
+        let _ = writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{}",
+            run.day,
+            s.part,
+            answer,
+            tsv_field(&run.input),
+            s.elapsed.as_nanos()
+        );
+    }
+    out
+}
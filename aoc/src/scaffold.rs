@@ -0,0 +1,199 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::days;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+
+fn fill(template: &str, day: u8) -> String {
+    template.replace("__DAY__", &day.to_string())
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+/// Inserts `line` before the first line equal to `before` that follows `after`.
+fn insert_line(text: &str, after: &str, before: &str, line: &str) -> Option<String> {
+    let start = text.find(after)? + after.len();
+    let at = start + text[start..].find(&format!("\n{}", before))? + 1;
+    Some(format!("{}{}\n{}", &text[..at], line, &text[at..]))
+}
+
+/// Edits `path` in memory, nothing is written until every edit succeeded.
+fn edit(
+    root: &Path,
+    path: &str,
+    change: impl FnOnce(&str) -> Option<String>,
+) -> io::Result<(PathBuf, String)> {
+    let full = root.join(path);
+    let text = fs::read_to_string(&full)?;
+    let text = change(&text)
+        .ok_or_else(|| invalid(format!("cannot find where to register in {}", path)))?;
+    Ok((full, text))
+}
+
+/// Creates the `dec-<day>` crate in the workspace at `root` and registers it
+/// with the workspace, the runner and the answer tests.
+///
+/// Days are found by their position in `days::DAYS`, so only the day after
+/// the last one can be created.
+pub fn new_day(root: &Path, day: u8) -> io::Result<()> {
+    let next = days::DAYS.len() + 1;
+    if day as usize != next || day > 25 {
+        return Err(invalid(format!("the next day to create is {}", next)));
+    }
+    let dir = root.join(format!("dec-{}", day));
+    if dir.exists() {
+        return Err(invalid(format!("{} already exists", dir.display())));
+    }
+
+    let edits = [
+        edit(root, "Cargo.toml", |text| {
+            insert_line(text, "members = [", "]", &format!("    \"dec-{}\",", day))
+        })?,
+        edit(root, "aoc/Cargo.toml", |text| {
+            let text = text.trim_end();
+            Some(format!(
+                "{}\ndec-{day} = {{ path = \"../dec-{day}\" }}\n",
+                text
+            ))
+        })?,
+        edit(root, "aoc/src/days.rs", |text| {
+            insert_line(
+                text,
+                "pub const DAYS",
+                "];",
+                &format!("    day!(dec_{day}::Day{day}),"),
+            )
+        })?,
+        edit(root, "aoc/tests/answers.rs", |text| {
+            let stub = format!(
+                "\n    dec_{day}_example: dec_{day}::Day{day}, include_str!(\"../../dec-{day}/input-test.txt\") => Answer::Unsolved, Answer::Unsolved;"
+            );
+            insert_line(text, "answers! {", "}", &stub)
+        })?,
+    ];
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join(".gitignore"), "/target\n")?;
+    fs::write(dir.join("Cargo.toml"), fill(CARGO_TOML, day))?;
+    fs::write(dir.join("src").join("lib.rs"), fill(LIB_RS, day))?;
+    fs::write(dir.join("input.txt"), "")?;
+    fs::write(dir.join("input-test.txt"), "")?;
+    for (path, text) in edits {
+        fs::write(path, text)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::workspace_root;
+
+    const REGISTERED: [&str; 4] = [
+        "Cargo.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/days.rs",
+        "aoc/tests/answers.rs",
+    ];
+
+    /// A copy of the files of the workspace `new_day` edits, removed once
+    /// dropped.
+    struct Workspace(PathBuf);
+
+    impl Workspace {
+        fn new(name: &str) -> Workspace {
+            let root =
+                std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", std::process::id(), name));
+            for path in REGISTERED {
+                let to = root.join(path);
+                fs::create_dir_all(to.parent().unwrap()).unwrap();
+                fs::copy(workspace_root().join(path), to).unwrap();
+            }
+            Workspace(root)
+        }
+
+        fn read(&self, path: &str) -> String {
+            fs::read_to_string(self.0.join(path)).unwrap()
+        }
+    }
+
+    impl Drop for Workspace {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn registers_the_next_day() {
+        let workspace = Workspace::new("next");
+        let day = days::DAYS.len() + 1;
+        new_day(&workspace.0, day as u8).unwrap();
+
+        let members = workspace.read("Cargo.toml");
+        let members = &members[members.find("members = [").unwrap()..];
+        let last = format!("\n    \"dec-{}\",\n]", day);
+        assert!(members.contains(&last), "{}", members);
+        assert!(workspace
+            .read("aoc/Cargo.toml")
+            .ends_with(&format!("\ndec-{day} = {{ path = \"../dec-{day}\" }}\n")));
+        assert!(workspace
+            .read("aoc/src/days.rs")
+            .contains(&format!("    day!(dec_{day}::Day{day}),\n];")));
+        assert!(workspace.read("aoc/tests/answers.rs").contains(&format!(
+            "    dec_{day}_example: dec_{day}::Day{day}, include_str!(\"../../dec-{day}/input-test.txt\") => Answer::Unsolved, Answer::Unsolved;\n}}"
+        )));
+
+        let dir = format!("dec-{}", day);
+        assert!(workspace
+            .read(&format!("{}/Cargo.toml", dir))
+            .contains(&format!("name = \"dec-{}\"", day)));
+        assert!(workspace
+            .read(&format!("{}/src/lib.rs", dir))
+            .contains(&format!("pub struct Day{}", day)));
+        assert_eq!(workspace.read(&format!("{}/input.txt", dir)), "");
+        assert_eq!(workspace.read(&format!("{}/input-test.txt", dir)), "");
+    }
+
+    #[test]
+    fn only_the_next_day_can_be_created() {
+        let workspace = Workspace::new("not-next");
+        let next = days::DAYS.len() + 1;
+        for day in [next - 1, next + 1] {
+            let err = new_day(&workspace.0, day as u8).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+            assert_eq!(
+                err.to_string(),
+                format!("the next day to create is {}", next)
+            );
+        }
+        for path in REGISTERED {
+            assert_eq!(
+                workspace.read(path),
+                fs::read_to_string(workspace_root().join(path)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn existing_days_are_left_alone() {
+        let workspace = Workspace::new("exists");
+        let day = days::DAYS.len() + 1;
+        let dir = workspace.0.join(format!("dec-{}", day));
+        fs::create_dir(&dir).unwrap();
+
+        let err = new_day(&workspace.0, day as u8).unwrap_err();
+        assert_eq!(err.to_string(), format!("{} already exists", dir.display()));
+        assert!(!dir.join("src").exists());
+        for path in REGISTERED {
+            assert_eq!(
+                workspace.read(path),
+                fs::read_to_string(workspace_root().join(path)).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,392 @@
+//! Tools looking into the puzzle of a day beyond its two answers.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Subcommand, ValueEnum};
+use common::{Error, Solution};
+use dec_2::analysis::Analysis;
+use dec_3::validate::Validator;
+use dec_3::Scanner;
+use dec_4::coverage::Coverage;
+use dec_4::Range;
+use dec_5::drawing::draw;
+use dec_5::replay::Replay;
+use dec_5::{message, CraneModel};
+
+use crate::input::{InputArgs, Source};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ReportFormat {
+    /// Aligned columns for the terminal
+    Table,
+    Csv,
+}
+
+/// How the second column of the day 2 strategy guide is read.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum GuideColumn {
+    /// The shape to play, as in part one
+    Shape,
+    /// The outcome of the round, as in part two
+    Outcome,
+}
+
+impl From<GuideColumn> for dec_2::Column {
+    fn from(column: GuideColumn) -> dec_2::Column {
+        match column {
+            GuideColumn::Shape => dec_2::Column::Shape,
+            GuideColumn::Outcome => dec_2::Column::Outcome,
+        }
+    }
+}
+
+/// What `aoc camp --draw` draws.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Drawing {
+    /// Each pair, marking its overlap and containment
+    Pairs,
+    /// Every assignment, then the number of elves per section
+    Camp,
+}
+
+/// Crane of day 5.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Crane {
+    /// CrateMover 9000, one crate at a time as in part one
+    #[value(name = "9000")]
+    CrateMover9000,
+    /// CrateMover 9001, all crates at once as in part two
+    #[value(name = "9001")]
+    CrateMover9001,
+}
+
+impl From<Crane> for CraneModel {
+    fn from(crane: Crane) -> CraneModel {
+        match crane {
+            Crane::CrateMover9000 => CraneModel::CrateMover9000,
+            Crane::CrateMover9001 => CraneModel::CrateMover9001,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Tool {
+    /// Statistics over the calories carried by the elves of day 1
+    Elves {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Number of buckets of the histogram of totals
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        buckets: u32,
+        #[arg(long, value_enum, default_value_t = ReportFormat::Table)]
+        format: ReportFormat,
+    },
+    /// Analyse the strategy guide of day 2 against the best play, possibly
+    /// under other rules
+    Strategy {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Rules of the game, see dec-2/rules. Rock paper scissors by default
+        #[arg(long)]
+        rules: Option<PathBuf>,
+        /// How the second column of the guide is read, both ways by default
+        #[arg(long, value_enum)]
+        column: Option<GuideColumn>,
+        /// Also show the play of every round
+        #[arg(long)]
+        rounds: bool,
+    },
+    /// Sum the priorities of the rucksacks of day 3, or validate them, one
+    /// line at a time
+    Rucksacks {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Number of elves sharing a badge
+        #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+        group: u32,
+        /// Report every rucksack and group breaking the rules of the puzzle
+        /// instead, failing if there is any
+        #[arg(long)]
+        validate: bool,
+    },
+    /// How the sections of the camp of day 4 are covered by all the elves
+    Camp {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Report the sections assigned to more than this many elves
+        #[arg(long, value_name = "K", default_value_t = 2)]
+        more_than: usize,
+        /// Sections of the camp, as `<first>-<last>`. From the first to the
+        /// last assigned section by default
+        #[arg(long)]
+        camp: Option<Range>,
+        /// Draw the assignments as in the puzzle instead
+        #[arg(long, value_enum)]
+        draw: Option<Drawing>,
+    },
+    /// Replay the rearrangement of the crates of day 5, drawing the stacks
+    /// after every step
+    Crates {
+        #[command(flatten)]
+        input: InputArgs,
+        #[arg(long, value_enum, default_value_t = Crane::CrateMover9000)]
+        crane: Crane,
+        /// Only draw the stacks once this many steps are done, 0 for the
+        /// starting stacks
+        #[arg(long, value_name = "N")]
+        step: Option<usize>,
+        /// Print the final stacks and the procedure bringing them back to the
+        /// starting ones instead, in the format of the input
+        #[arg(long, conflicts_with = "step")]
+        inverse: bool,
+    },
+}
+
+/// Reads and parses the input of a day, reporting what went wrong on stderr.
+fn load<S: Solution>(day: u8, input: &InputArgs) -> Option<S::Input> {
+    let source = input.source(day);
+    let text = crate::read(&source)?;
+    match S::parse(&text) {
+        Ok(parsed) => Some(parsed),
+        Err(err) => {
+            eprintln!("{}", err.in_file(source.to_string()));
+            None
+        }
+    }
+}
+
+fn rules(path: Option<PathBuf>) -> Option<dec_2::rules::Rules> {
+    let Some(path) = path else {
+        return Some(dec_2::rules::Rules::classic());
+    };
+    let source = Source::File(path);
+    let config = crate::read(&source)?;
+    match dec_2::rules::Rules::parse(&config) {
+        Ok(rules) => Some(rules),
+        Err(err) => {
+            eprintln!("{}", err.in_file(source.to_string()));
+            None
+        }
+    }
+}
+
+fn elves(input: InputArgs, buckets: u32, format: ReportFormat) -> ExitCode {
+    let Some(elves) = load::<dec_1::Day1>(1, &input) else {
+        return ExitCode::FAILURE;
+    };
+    let report = dec_1::report::Report::new(&elves, buckets as usize);
+    match format {
+        ReportFormat::Table => print!("{}", report.table()),
+        ReportFormat::Csv => print!("{}", report.csv()),
+    }
+    ExitCode::SUCCESS
+}
+
+fn strategy(
+    input: InputArgs,
+    path: Option<PathBuf>,
+    column: Option<GuideColumn>,
+    rounds: bool,
+) -> ExitCode {
+    let Some(rules) = rules(path) else {
+        return ExitCode::FAILURE;
+    };
+    let source = input.source(2);
+    let Some(guide) = crate::read(&source) else {
+        return ExitCode::FAILURE;
+    };
+    let columns = match column {
+        Some(column) => vec![column.into()],
+        None => vec![dec_2::Column::Shape, dec_2::Column::Outcome],
+    };
+    match Analysis::new(&rules, &guide, &columns) {
+        Ok(analysis) => {
+            print!("{}", analysis.report(&rules, rounds));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err.in_file(source.to_string()));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Calls `f` with the number and text of every line of `source`, without
+/// reading it all at once. Stops at the first error, reported on stderr.
+fn each_line(source: &Source, mut f: impl FnMut(usize, &str) -> Result<(), Error>) -> bool {
+    let mut reader = match source.open() {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", source, err);
+            return false;
+        }
+    };
+    let mut line = String::new();
+    for number in 1.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("Cannot read {}: {}", source, err);
+                return false;
+            }
+        }
+        let text = line.strip_suffix('\n').unwrap_or(&line);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        if let Err(err) = f(number, text) {
+            eprintln!("{}", err.in_file(source.to_string()));
+            return false;
+        }
+    }
+    true
+}
+
+fn rucksacks(input: InputArgs, group: u32, validate: bool) -> ExitCode {
+    let source = input.source(3);
+    if validate {
+        let mut validator = Validator::new(group as usize);
+        let read = each_line(&source, |number, text| {
+            validator.push(number, text);
+            Ok(())
+        });
+        if !read {
+            return ExitCode::FAILURE;
+        }
+        let anomalies = validator.finish();
+        for anomaly in &anomalies {
+            println!("{}:{}", source, anomaly);
+        }
+        if anomalies.is_empty() {
+            return ExitCode::SUCCESS;
+        }
+        eprintln!("{} anomalies in {}", anomalies.len(), source);
+        return ExitCode::FAILURE;
+    }
+
+    let mut scanner = Scanner::new(group as usize);
+    if !each_line(&source, |number, text| scanner.push(number, text)) {
+        return ExitCode::FAILURE;
+    }
+    let sums = scanner.sums();
+    println!("Rucksacks: {}", sums.rucksacks);
+    println!("Priorities of shared items: {}", sums.shared);
+    println!("Groups of {}: {}", group, sums.groups);
+    println!("Priorities of badges: {}", sums.badges);
+    let left = sums.rucksacks - sums.groups * group as usize;
+    if left > 0 {
+        println!("Rucksacks left out of a group: {}", left);
+    }
+    ExitCode::SUCCESS
+}
+
+fn camp(input: InputArgs, k: usize, camp: Option<Range>, draw: Option<Drawing>) -> ExitCode {
+    let Some(pairs) = load::<dec_4::Day4>(4, &input) else {
+        return ExitCode::FAILURE;
+    };
+    if let Some(drawing) = draw {
+        // The puzzle draws from section 1, unless some elf cleans section 0.
+        let first = pairs
+            .iter()
+            .map(|(a, b)| a.start().min(b.start()).min(1))
+            .min();
+        let last = pairs.iter().map(|(a, b)| a.end().max(b.end())).max();
+        let Some(span) = camp.or_else(|| Range::new(first?, last?)) else {
+            return ExitCode::SUCCESS;
+        };
+        match drawing {
+            Drawing::Pairs => print!("{}", dec_4::draw::pairs(&pairs, span)),
+            Drawing::Camp => print!("{}", dec_4::draw::camp(&pairs, span)),
+        }
+        return ExitCode::SUCCESS;
+    }
+    let coverage = Coverage::new(&pairs, k, camp);
+    match coverage.camp {
+        Some(camp) => println!("Camp: sections {} ({} sections)", camp, camp.len()),
+        None => println!("Camp: no sections"),
+    }
+    let sets = [
+        ("Covered", &coverage.covered),
+        ("Covered by nobody", &coverage.uncovered),
+        (
+            &format!("Covered by more than {} elves", k),
+            &coverage.crowded,
+        ),
+    ];
+    for (name, set) in sets {
+        println!("{}: {} sections, {}", name, set.len(), set);
+    }
+    println!("Redundant pairs: {}", coverage.redundant.len());
+    for &i in &coverage.redundant {
+        let (a, b) = pairs[i];
+        println!("  line {}: {},{}", i + 1, a, b);
+    }
+    ExitCode::SUCCESS
+}
+
+fn crates(input: InputArgs, crane: Crane, step: Option<usize>, inverse: bool) -> ExitCode {
+    // Loading reports the first illegal step as a parse error.
+    let Some(plan) = load::<dec_5::Day5>(5, &input) else {
+        return ExitCode::FAILURE;
+    };
+    let mut replay = Replay::new(&plan, crane.into());
+    if inverse {
+        replay.jump(plan.moves.len());
+        println!("{}", draw(replay.stacks()));
+        for m in dec_5::inverse(&plan.moves) {
+            println!("{}", m);
+        }
+        return ExitCode::SUCCESS;
+    }
+    if let Some(n) = step {
+        replay.jump(n);
+        if replay.done() < n {
+            eprintln!("The procedure has only {} steps", replay.done());
+            return ExitCode::FAILURE;
+        }
+        print!("{}", draw(replay.stacks()));
+        return ExitCode::SUCCESS;
+    }
+    println!("Step 0, the starting stacks:");
+    print!("{}", draw(replay.stacks()));
+    while let Some(m) = replay.step() {
+        println!("\nStep {}, {} (line {}):", replay.done(), m, m.line);
+        print!("{}", draw(replay.stacks()));
+    }
+    println!("\nMessage: {}", message(replay.stacks()));
+    ExitCode::SUCCESS
+}
+
+pub fn run(tool: Tool) -> ExitCode {
+    match tool {
+        Tool::Elves {
+            input,
+            buckets,
+            format,
+        } => elves(input, buckets, format),
+        Tool::Strategy {
+            input,
+            rules,
+            column,
+            rounds,
+        } => strategy(input, rules, column, rounds),
+        Tool::Rucksacks {
+            input,
+            group,
+            validate,
+        } => rucksacks(input, group, validate),
+        Tool::Camp {
+            input,
+            more_than,
+            camp: sections,
+            draw,
+        } => camp(input, more_than, sections, draw),
+        Tool::Crates {
+            input,
+            crane,
+            step,
+            inverse,
+        } => crates(input, crane, step, inverse),
+    }
+}
@@ -0,0 +1,106 @@
+//! Known answers of every day, from the worked examples of the puzzle text and
+//! for the real input. Only the answers of day 3 were accepted by the puzzle,
+//! the other real-input values are outputs of the solvers recorded to catch
+//! regressions.
+
+use common::{Answer, Solution};
+
+fn check<S: Solution>(input: &str, part1: Answer, part2: Answer) {
+    let answers = common::run::<S>(input, None).map(|solved| {
+        solved
+            .into_iter()
+            .map(|s| (s.part, s.answer))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(answers, Ok(vec![(1, part1), (2, part2)]));
+}
+
+macro_rules! answers {
+    ($($(#[$attr:meta])* $name:ident: $day:ty, $input:expr => $part1:expr, $part2:expr;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                check::<$day>($input, Answer::from($part1), Answer::from($part2));
+            }
+        )*
+    };
+}
+
+const DEC_4_EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+const DEC_10_EXAMPLE_CRT: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+const DEC_10_CRT: &str = "####.####.####..##..#..#...##..##..###..
+#.......#.#....#..#.#..#....#.#..#.#..#.
+###....#..###..#....####....#.#..#.###..
+#.....#...#....#....#..#....#.####.#..#.
+#....#....#....#..#.#..#.#..#.#..#.#..#.
+####.####.#.....##..#..#..##..#..#.###..";
+
+answers! {
+    dec_1_example: dec_1::Day1, include_str!("../../dec-1/input-test.txt") => 24000, 45000;
+    dec_1: dec_1::Day1, include_str!("../../dec-1/input.txt") => 68442, 204837;
+
+    dec_2_example: dec_2::Day2, include_str!("../../dec-2/input-test.txt") => 15, 12;
+    dec_2: dec_2::Day2, include_str!("../../dec-2/input.txt") => 13675, 14184;
+
+    dec_3_example: dec_3::Day3, include_str!("../../dec-3/input-test.txt") => 157, 70;
+    // Accepted by the puzzle.
+    dec_3: dec_3::Day3, include_str!("../../dec-3/input.txt") => 8401, 2641;
+
+    dec_4_example: dec_4::Day4, DEC_4_EXAMPLE => 2, 4;
+    dec_4: dec_4::Day4, include_str!("../../dec-4/input.txt") => 562, 924;
+
+    dec_5_example: dec_5::Day5, include_str!("../../dec-5/input-test.txt") => "CMZ", "MCD";
+    dec_5: dec_5::Day5, include_str!("../../dec-5/input.txt") => "JDTMRWCQJ", "VHJDDCWRD";
+
+    dec_6_example_1: dec_6::Day6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => 7, 19;
+    dec_6_example_2: dec_6::Day6, "bvwbjplbgvbhsrlpgdmjqwftvncz" => 5, 23;
+    dec_6_example_3: dec_6::Day6, "nppdvjthqldpwncqszvftbrmjlhg" => 6, 23;
+    dec_6_example_4: dec_6::Day6, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg" => 10, 29;
+    dec_6_example_5: dec_6::Day6, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => 11, 26;
+    dec_6: dec_6::Day6, include_str!("../../dec-6/input.txt") => 1892, 2313;
+
+    dec_7_example: dec_7::Day7, include_str!("../../dec-7/input-test.txt") => 95437, 24933642;
+    dec_7: dec_7::Day7, include_str!("../../dec-7/input.txt") => 1086293, 366028;
+
+    dec_8_example: dec_8::Day8, include_str!("../../dec-8/input-test.txt") => 21, 8;
+    dec_8: dec_8::Day8, include_str!("../../dec-8/input.txt") => 1832, 157320;
+
+    dec_9_example: dec_9::Day9, include_str!("../../dec-9/input-test.txt") => 13, 1;
+    dec_9_example_2: dec_9::Day9, include_str!("../../dec-9/input-test2.txt") => 88, 36;
+    dec_9: dec_9::Day9, include_str!("../../dec-9/input.txt") => 6236, 2449;
+
+    dec_10_example: dec_10::Day10, include_str!("../../dec-10/input-test2.txt") => 13140, DEC_10_EXAMPLE_CRT;
+    dec_10: dec_10::Day10, include_str!("../../dec-10/input.txt") => 13180, DEC_10_CRT;
+
+    dec_11_example: dec_11::Day11, include_str!("../../dec-11/input-test.txt") => 10605, 2713310158u64;
+    dec_11: dec_11::Day11, include_str!("../../dec-11/input.txt") => 117624, 16792940265u64;
+
+    dec_12_example: dec_12::Day12, include_str!("../../dec-12/input-test.txt") => 31, 29;
+    #[ignore = "slow, run with --release -- --ignored"]
+    dec_12: dec_12::Day12, include_str!("../../dec-12/input.txt") => 449, 443;
+
+    dec_13_example: dec_13::Day13, include_str!("../../dec-13/input-test.txt") => 13, Answer::Unsolved;
+    dec_13: dec_13::Day13, include_str!("../../dec-13/input.txt") => 5659, Answer::Unsolved;
+
+    dec_14_example: dec_14::Day14, include_str!("../../dec-14/input-test.txt") => 24, 93;
+    #[ignore = "slow, run with --release -- --ignored"]
+    dec_14: dec_14::Day14, include_str!("../../dec-14/input.txt") => 698, 28594;
+
+    dec_15_example: dec_15::Day15, include_str!("../../dec-15/input-test.txt") => 26, 56000011u64;
+    #[ignore = "slow, run with --release -- --ignored"]
+    dec_15: dec_15::Day15, include_str!("../../dec-15/input.txt") => 5461729, 10621647166538u64;
+}
@@ -0,0 +1,97 @@
+//! Ranking of the elves by the calories they carry, and statistics over them.
+
+use common::Solution;
+use dec_1::report::{Bucket, Report};
+use dec_1::{top_n, Day1, Elf};
+
+fn elves() -> Vec<Elf> {
+    Day1::parse(include_str!("../../dec-1/input-test.txt")).unwrap()
+}
+
+#[test]
+fn top_n_ranks_elves_with_their_items() {
+    assert_eq!(
+        top_n(&elves(), 3),
+        vec![
+            Elf {
+                index: 3,
+                total: 24000,
+                items: 3,
+                largest: 9000,
+            },
+            Elf {
+                index: 2,
+                total: 11000,
+                items: 2,
+                largest: 6000,
+            },
+            Elf {
+                index: 4,
+                total: 10000,
+                items: 1,
+                largest: 10000,
+            },
+        ]
+    );
+}
+
+#[test]
+fn top_n_keeps_input_order_on_ties_and_allows_large_n() {
+    let elves = Day1::parse("1\n\n2\n\n1\n").unwrap();
+    let ranked: Vec<_> = top_n(&elves, 100).iter().map(|e| e.index).collect();
+    assert_eq!(ranked, vec![1, 0, 2]);
+    assert!(top_n(&elves, 0).is_empty());
+}
+
+#[test]
+fn top_n_ranks_any_part_of_the_input() {
+    let elves = Day1::parse("1\n\n5\n\n3\n").unwrap();
+    let totals = |ranked: Vec<Elf>| {
+        ranked
+            .iter()
+            .map(|e| (e.index, e.total))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(totals(top_n(&elves[1..], 1)), vec![(1, 5)]);
+    assert_eq!(totals(top_n(&elves[2..], 1)), vec![(2, 3)]);
+    assert_eq!(totals(top_n(&elves[..2], 2)), vec![(1, 5), (0, 1)]);
+}
+
+#[test]
+fn trailing_separator_adds_no_empty_elf() {
+    let elves = Day1::parse("1000\n\n").unwrap();
+    assert_eq!(elves.len(), 1);
+    let report = Report::new(&elves, 2);
+    assert_eq!((report.elves, report.empty), (1, 0));
+    assert_eq!(Day1::parse("1000\n\n\n").unwrap().len(), 2);
+    assert!(Day1::parse("").unwrap().is_empty());
+}
+
+#[test]
+fn report_describes_the_inventory() {
+    let report = Report::new(&Day1::parse("1000\n2000\n\n\n5000\n\n4000\n").unwrap(), 2);
+    assert_eq!(report.elves, 4);
+    assert_eq!(report.empty, 1);
+    assert_eq!(report.mean, Some(3000.0));
+    assert_eq!(report.median, Some(3500.0));
+    assert_eq!(report.percentiles[0], (25, 0));
+    assert_eq!(report.largest_item, Some(5000));
+    assert_eq!(
+        report.histogram,
+        vec![
+            Bucket {
+                from: 0,
+                to: 2500,
+                count: 1
+            },
+            Bucket {
+                from: 2501,
+                to: 5000,
+                count: 3
+            },
+        ]
+    );
+    assert!(report
+        .csv()
+        .ends_with("histogram 0-2500,1\nhistogram 2501-5000,3\n"));
+}
@@ -0,0 +1,33 @@
+//! Row and search area of the sensor report, given by the input and never guessed.
+
+use common::{Answer, Error, Solution};
+use dec_15::Day15;
+
+const EXAMPLE: &str = include_str!("../../dec-15/input-test.txt");
+
+#[test]
+fn header_sets_row_and_area() {
+    let report = Day15::parse(EXAMPLE).unwrap();
+    assert_eq!((report.row, report.area), (10, 20));
+    assert_eq!(report.pairs.len(), 14);
+
+    let report = Day15::parse(&EXAMPLE.replacen("row=10, area=20", "row=11, area=30", 1)).unwrap();
+    assert_eq!((report.row, report.area), (11, 30));
+}
+
+#[test]
+fn small_inputs_without_header_use_the_puzzle_row() {
+    let pairs = EXAMPLE.split_once('\n').unwrap().1;
+    let report = Day15::parse(pairs).unwrap();
+    assert_eq!((report.row, report.area), (2000000, 4000000));
+    let solved = common::run::<Day15>(pairs, Some(1)).unwrap();
+    assert_eq!(solved[0].answer, Answer::from(0));
+}
+
+#[test]
+fn malformed_header() {
+    assert_eq!(
+        Day15::parse("row=ten, area=20\n").err(),
+        Some(Error::unexpected(1, 5, "a row", "ten"))
+    );
+}
@@ -0,0 +1,102 @@
+//! Rules of rock paper scissors and its variants, and the two readings of the
+//! strategy guide.
+
+use common::{Error, Solution};
+use dec_2::analysis::Analysis;
+use dec_2::rules::Rules;
+use dec_2::{total_score, Code, Column, Day2, Outcome, Round, Shape};
+
+#[test]
+fn relations_agree_with_outcomes() {
+    for shape in Shape::ALL {
+        assert_eq!(shape.beats().loses_to(), shape);
+        assert_eq!(shape.against(shape.beats()), Outcome::Win);
+        assert_eq!(shape.against(shape.loses_to()), Outcome::Loss);
+        assert_eq!(shape.against(shape), Outcome::Draw);
+        for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+            assert_eq!(outcome.shape_against(shape).against(shape), outcome);
+        }
+    }
+}
+
+#[test]
+fn second_column_is_a_shape_or_an_outcome() {
+    let rounds = Day2::parse("A Y\n").unwrap();
+    let round = Round {
+        opponent: Shape::Rock,
+        code: Code::Y,
+    };
+    assert_eq!(rounds, vec![round]);
+    assert_eq!(round.play(Column::Shape), Shape::Paper);
+    assert_eq!(round.score(Column::Shape), 8);
+    assert_eq!(round.play(Column::Outcome), Shape::Rock);
+    assert_eq!(round.score(Column::Outcome), 4);
+}
+
+#[test]
+fn classic_rules_score_like_the_puzzle() {
+    let input = include_str!("../../dec-2/input.txt");
+    let rules = Rules::classic();
+    let rounds = Day2::parse(input).unwrap();
+    for column in [Column::Shape, Column::Outcome] {
+        let plays = rules.parse_guide(input, column).unwrap();
+        assert_eq!(rules.total_score(&plays), total_score(&rounds, column));
+    }
+}
+
+#[test]
+fn every_shape_beats_half_of_the_others() {
+    for config in [
+        include_str!("../../dec-2/rules/rpsls.txt"),
+        include_str!("../../dec-2/rules/rps7.txt"),
+    ] {
+        let rules = Rules::parse(config).unwrap();
+        for a in 0..rules.len() {
+            let beaten = (0..rules.len()).filter(|&b| rules.beats(a, b)).count();
+            assert_eq!(beaten, rules.len() / 2);
+            for b in 0..rules.len() {
+                assert!(a == b || rules.beats(a, b) != rules.beats(b, a));
+            }
+        }
+    }
+    let rpsls = Rules::parse(include_str!("../../dec-2/rules/rpsls.txt")).unwrap();
+    let shape = |name| rpsls.shapes.iter().position(|s| s.name == name).unwrap();
+    assert!(rpsls.beats(shape("spock"), shape("scissors")));
+    assert!(rpsls.beats(shape("lizard"), shape("paper")));
+    assert!(rpsls.beats(shape("rock"), shape("lizard")));
+}
+
+#[test]
+fn rules_need_an_odd_number_of_shapes() {
+    let config =
+        "shape a A X 1\nshape b B Y 2\noutcome loss X 0\noutcome draw Y 3\noutcome win Z 6\n";
+    assert_eq!(
+        Rules::parse(config).err(),
+        Some(Error::end_of_input(
+            6,
+            "another shape, a cyclic game needs an odd number of at least 3 shapes"
+        ))
+    );
+}
+
+#[test]
+fn analysis_compares_the_guide_with_the_best_play() {
+    let rules = Rules::classic();
+    let guide = include_str!("../../dec-2/input-test.txt");
+    let analysis = Analysis::new(&rules, guide, &[Column::Shape, Column::Outcome]).unwrap();
+    let summary: Vec<_> = analysis
+        .readings
+        .iter()
+        .map(|r| (r.score, r.wins, r.draws, r.losses))
+        .collect();
+    assert_eq!(summary, vec![(15, 1, 1, 1), (12, 1, 1, 1)]);
+    assert_eq!(analysis.readings[0].scores, vec![8, 1, 6]);
+    assert_eq!(analysis.best, 24);
+    assert!(analysis
+        .optimal
+        .iter()
+        .all(|p| rules.outcome(p.mine, p.opponent) == Outcome::Win));
+    let alone = Analysis::new(&rules, guide, &[]).unwrap();
+    assert!(alone.readings.is_empty());
+    assert_eq!((alone.optimal, alone.best), (analysis.optimal, 24));
+}
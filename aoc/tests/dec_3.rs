@@ -0,0 +1,102 @@
+//! Item sets of the rucksacks, badges of groups of any size and validation.
+
+use common::{Error, Solution};
+use dec_3::validate::{Anomaly, Validator};
+use dec_3::{badges, priority, Day3, Items, Scanner};
+
+const EXAMPLE: &str = include_str!("../../dec-3/input-test.txt");
+
+#[test]
+fn priorities_and_item_sets() {
+    assert_eq!(priority(b'a'), Some(1));
+    assert_eq!(priority(b'Z'), Some(52));
+    assert_eq!(priority(b' '), None);
+    let items = Items::from_bytes(b"vJrwpWtwJgWr");
+    assert_eq!(items.len(), 8);
+    let shared = items.intersection(Items::from_bytes(b"hcsFMMfFFhFp"));
+    assert_eq!(shared.iter().collect::<String>(), "p");
+    assert_eq!(shared.priority(), 16);
+}
+
+#[test]
+fn badges_of_any_group_size() {
+    let rucksacks = Day3::parse(EXAMPLE).unwrap();
+    let letters = |size| {
+        badges(&rucksacks, size)
+            .map(|b| b.iter().collect::<String>())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(letters(3), vec!["r", "Z"]);
+    assert_eq!(letters(6), vec![""]);
+    assert_eq!(letters(1).len(), 6);
+}
+
+#[test]
+fn odd_rucksacks_are_rejected() {
+    assert_eq!(
+        Day3::parse("abcAbc\nabXab\n").err(),
+        Some(Error::unexpected(2, 1, "an even number of items", "abXab"))
+    );
+    assert!(Scanner::new(3).push(1, "abXab").is_err());
+}
+
+#[test]
+fn scanner_sums_like_both_parts() {
+    let mut scanner = Scanner::new(3);
+    for (i, line) in EXAMPLE.lines().enumerate() {
+        scanner.push(i + 1, line).unwrap();
+    }
+    let sums = scanner.sums();
+    assert_eq!((sums.rucksacks, sums.shared), (6, 157));
+    assert_eq!((sums.groups, sums.badges), (2, 70));
+}
+
+#[test]
+fn validator_reports_every_anomaly() {
+    let mut validator = Validator::new(2);
+    for (i, line) in ["abcAbc", "ab1ab", "xyzxyq"].iter().enumerate() {
+        validator.push(i + 1, line);
+    }
+    assert_eq!(
+        validator.finish(),
+        vec![
+            Anomaly::SharedItems {
+                line: 1,
+                items: "bc".to_string()
+            },
+            Anomaly::OddLength { line: 2, items: 5 },
+            Anomaly::NotALetter {
+                line: 2,
+                column: 3,
+                found: '1'
+            },
+            Anomaly::SharedItems {
+                line: 2,
+                items: "ab".to_string()
+            },
+            Anomaly::Badges {
+                first: 1,
+                last: 2,
+                items: "ab".to_string()
+            },
+            Anomaly::SharedItems {
+                line: 3,
+                items: "xy".to_string()
+            },
+            Anomaly::IncompleteGroup {
+                first: 3,
+                last: 3,
+                size: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn puzzle_inputs_are_valid() {
+    let mut validator = Validator::new(3);
+    for (i, line) in include_str!("../../dec-3/input.txt").lines().enumerate() {
+        validator.push(i + 1, line);
+    }
+    assert_eq!(validator.finish(), vec![]);
+}
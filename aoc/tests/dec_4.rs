@@ -0,0 +1,92 @@
+//! Interval algebra of the section ranges and coverage of the whole camp.
+
+use common::{Error, Solution};
+use dec_4::coverage::Coverage;
+use dec_4::set::IntervalSet;
+use dec_4::{draw, Day4, Range};
+
+fn range(s: &str) -> Range {
+    s.parse().unwrap()
+}
+
+#[test]
+fn range_algebra() {
+    let (a, b) = (range("2-8"), range("3-7"));
+    assert_eq!(a.len(), 7);
+    assert!(a.contains(&b) && !b.contains(&a));
+    assert!(range("5-7").overlaps(&range("7-9")));
+    assert!(!range("2-4").overlaps(&range("6-8")));
+    assert_eq!(range("5-7").intersection(&range("7-9")), Some(range("7-7")));
+    assert_eq!(range("2-4").intersection(&range("6-8")), None);
+    assert_eq!(range("2-4").union(&range("5-8")), Some(range("2-8")));
+    assert_eq!(range("2-4").union(&range("6-8")), None);
+    assert_eq!(a.difference(&b), vec![range("2-2"), range("8-8")]);
+    assert_eq!(b.difference(&a), vec![]);
+    assert_eq!(range("2-4").difference(&range("4-9")), vec![range("2-3")]);
+    assert_eq!(range("12-40").to_string(), "12-40");
+    assert_eq!(range("0-4294967295").len(), 1 << 32);
+}
+
+#[test]
+fn malformed_ranges_are_errors() {
+    assert_eq!(
+        Day4::parse("6-4,1-2\n").err(),
+        Some(Error::unexpected(1, 1, "an ascending range", "6-4"))
+    );
+    assert_eq!(
+        Day4::parse("1-2,3-x\n").err(),
+        Some(Error::unexpected(1, 7, "a section", "x"))
+    );
+    assert_eq!(
+        Day4::parse("1-2,34\n").err(),
+        Some(Error::unexpected(1, 7, "`-`", ""))
+    );
+}
+
+#[test]
+fn interval_sets_stay_normalised() {
+    let set: IntervalSet = ["6-8", "2-3", "4-4", "12-15", "7-10"]
+        .iter()
+        .map(|s| range(s))
+        .collect();
+    assert_eq!(set.to_string(), "2-4,6-10,12-15");
+    assert_eq!(set.len(), 12);
+    assert!(set.contains(&range("7-9")) && !set.contains(&range("4-6")));
+    assert_eq!(
+        set.complement(range("1-16")).to_string(),
+        "1-1,5-5,11-11,16-16"
+    );
+    assert_eq!(set.difference(&range("3-13")).to_string(), "2-2,14-15");
+    let whole: IntervalSet = [range("0-4294967295"), range("7-9")].into_iter().collect();
+    assert_eq!(whole.len(), 1 << 32);
+}
+
+#[test]
+fn coverage_of_the_example_camp() {
+    let pairs = Day4::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n").unwrap();
+    let coverage = Coverage::new(&pairs, 4, Some(range("1-10")));
+    assert_eq!(coverage.covered.to_string(), "2-9");
+    assert_eq!(coverage.uncovered.to_string(), "1-1,10-10");
+    assert_eq!(coverage.crowded.to_string(), "3-7");
+    // 9 is only cleaned by the third pair.
+    assert_eq!(coverage.redundant, vec![0, 1, 3, 4, 5]);
+
+    let inside = Coverage::new(&pairs, 4, Some(range("4-6")));
+    assert_eq!(inside.covered.to_string(), "4-6");
+    assert_eq!(inside.uncovered.to_string(), "-");
+    assert_eq!(inside.crowded.to_string(), "4-6");
+}
+
+#[test]
+fn drawings_follow_the_puzzle() {
+    let pairs = Day4::parse("2-8,3-7\n").unwrap();
+    assert_eq!(
+        draw::pairs(&pairs, range("1-9")),
+        ".2345678.  2-8 contains 3-7\n..34567..  3-7\n..XXXXX..  overlap 3-7\n"
+    );
+    let pairs = Day4::parse("8-12,11-11\n").unwrap();
+    assert_eq!(
+        draw::camp(&pairs, range("7-13")),
+        "   1111\n7890123\n.89012.  8-12\n....1..  11-11\n.11121.  elves per section\n"
+    );
+}
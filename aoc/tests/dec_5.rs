@@ -0,0 +1,219 @@
+//! Drawings of the crate stacks and the rearrangement procedure.
+
+use common::{Error, Solution};
+use dec_5::drawing::draw;
+use dec_5::history::History;
+use dec_5::replay::Replay;
+use dec_5::{
+    inverse, message, rearrange, validate, CraneModel, Day5, Illegal, IllegalMove, Move, Plan,
+};
+
+#[test]
+fn drawing_with_long_labels_and_gaps() {
+    let plan =
+        Day5::parse("[AB]      [X]\n[CD] [E]  [Y]\n  1   2    3\n\nmove 2 from 3 to 2\n").unwrap();
+    assert_eq!(
+        plan.stacks,
+        vec![vec!["CD", "AB"], vec!["E"], vec!["Y", "X"]]
+    );
+    assert_eq!(message(&plan.stacks), "ABEX");
+    assert_eq!(
+        plan.moves,
+        vec![Move {
+            count: 2,
+            from: 2,
+            to: 1,
+            line: 5
+        }]
+    );
+}
+
+#[test]
+fn malformed_drawings() {
+    assert_eq!(
+        Day5::parse("    [A]\n[B]\n 1   2\n\n").err(),
+        Some(Error::unexpected(
+            1,
+            5,
+            "a crate resting on another one",
+            "[A]"
+        ))
+    );
+    assert_eq!(
+        Day5::parse("[A]     [B]\n 1   2\n\n").err(),
+        Some(Error::unexpected(
+            1,
+            9,
+            "a crate above a stack number",
+            "[B]"
+        ))
+    );
+    assert_eq!(
+        Day5::parse("[A] [B]\n 1   3\n\n").err(),
+        Some(Error::unexpected(2, 6, "stack number 2", "3"))
+    );
+    assert_eq!(
+        Day5::parse("move 1 from 2 to 1\n").err(),
+        Some(Error::end_of_input(2, "an empty line after the drawing"))
+    );
+}
+
+#[test]
+fn crane_models_keep_or_reverse_the_order() {
+    let plan = Day5::parse(include_str!("../../dec-5/input-test.txt")).unwrap();
+    let first_two = |crane: CraneModel| {
+        let mut stacks = plan.stacks.clone();
+        for m in &plan.moves[..2] {
+            crane.apply(&mut stacks, m).unwrap();
+        }
+        stacks
+    };
+    assert_eq!(
+        first_two(CraneModel::CrateMover9000)[2],
+        vec!["P", "D", "N", "Z"]
+    );
+    assert_eq!(
+        first_two(CraneModel::CrateMover9001)[2],
+        vec!["P", "Z", "N", "D"]
+    );
+    assert_eq!(
+        rearrange(&plan, CraneModel::CrateMover9001).unwrap(),
+        vec![vec!["M"], vec!["C"], vec!["P", "Z", "N", "D"]]
+    );
+}
+
+#[test]
+fn drawings_read_back_the_same_stacks() {
+    let example = include_str!("../../dec-5/input-test.txt");
+    let plan = Day5::parse(example).unwrap();
+    assert!(example.starts_with(&draw(&plan.stacks)));
+
+    let stacks = vec![
+        vec!["AB".to_string(), "C".to_string()],
+        vec![],
+        vec!["DEF".to_string()],
+    ];
+    let drawing = draw(&stacks);
+    assert_eq!(drawing, "[C]\n[AB]        [DEF]\n  1     2     3\n");
+    assert_eq!(
+        Day5::parse(&format!("{}\n", drawing)).unwrap().stacks,
+        stacks
+    );
+}
+
+#[test]
+fn replay_steps_and_jumps() {
+    let plan = Day5::parse(include_str!("../../dec-5/input-test.txt")).unwrap();
+    let mut replay = Replay::new(&plan, CraneModel::CrateMover9000);
+    assert_eq!(
+        replay.step().map(|m| m.to_string()),
+        Some("move 1 from 2 to 1".to_string())
+    );
+    replay.jump(4);
+    assert_eq!(message(replay.stacks()), "CMZ");
+    assert_eq!(replay.step(), None);
+    replay.jump(0);
+    assert_eq!(replay.stacks(), &plan.stacks);
+}
+
+#[test]
+fn illegal_steps_are_reported_on_their_line() {
+    let drawing = "[A]\n[B] [C]\n 1   2\n\nmove 1 from 2 to 1\n";
+    assert_eq!(
+        Day5::parse(&format!("{}move 4 from 1 to 2\n", drawing)).err(),
+        Some(Error::unexpected(6, 6, "at most 3 crates", "4"))
+    );
+    let first = |step: &str| Day5::parse(&format!("[A]\n[B] [C]\n 1   2\n\n{}\n", step)).err();
+    assert_eq!(
+        first("move 1 from 3 to 1"),
+        Some(Error::unexpected(5, 13, "a stack from 1 to 2", "3"))
+    );
+    assert_eq!(
+        first("move 1 from 1 to 3"),
+        Some(Error::unexpected(5, 18, "a stack from 1 to 2", "3"))
+    );
+    assert_eq!(
+        first("move 1 from 2 to 2"),
+        Some(Error::unexpected(5, 18, "a stack other than 2", "2"))
+    );
+
+    let plan = Plan {
+        stacks: Day5::parse(drawing).unwrap().stacks,
+        moves: vec![
+            Move {
+                count: 2,
+                from: 0,
+                to: 1,
+                line: 5,
+            },
+            Move {
+                count: 5,
+                from: 1,
+                to: 0,
+                line: 6,
+            },
+        ],
+    };
+    let illegal = validate(&plan).unwrap_err();
+    assert_eq!(
+        illegal,
+        IllegalMove {
+            step: 1,
+            m: plan.moves[1],
+            reason: Illegal::NotEnoughCrates { wanted: 5, held: 3 },
+        }
+    );
+    assert_eq!(
+        illegal.to_string(),
+        "6: move 5 from 2 to 1: 5 crates are taken from a stack of 3"
+    );
+    assert_eq!(rearrange(&plan, CraneModel::CrateMover9000), Err(illegal));
+    let mut replay = Replay::new(&plan, CraneModel::CrateMover9001);
+    replay.jump(2);
+    assert_eq!(replay.done(), 1);
+}
+
+#[test]
+fn inverse_procedures_restore_the_starting_stacks() {
+    let plan = Day5::parse(include_str!("../../dec-5/input.txt")).unwrap();
+    for crane in [CraneModel::CrateMover9000, CraneModel::CrateMover9001] {
+        let back = Plan {
+            stacks: rearrange(&plan, crane).unwrap(),
+            moves: inverse(&plan.moves),
+        };
+        assert_eq!(rearrange(&back, crane), Ok(plan.stacks.clone()));
+    }
+}
+
+#[test]
+fn history_undoes_and_redoes_steps() {
+    let plan = Day5::parse(include_str!("../../dec-5/input-test.txt")).unwrap();
+    let mut history = History::new(plan.stacks.clone(), CraneModel::CrateMover9000);
+    for m in &plan.moves[..2] {
+        history.apply(*m).unwrap();
+    }
+    let after_two = history.stacks().clone();
+    assert_eq!(
+        history.apply(Move {
+            count: 9,
+            from: 0,
+            to: 1,
+            line: 0
+        }),
+        Err(Illegal::NotEnoughCrates { wanted: 9, held: 0 })
+    );
+    assert_eq!(history.stacks(), &after_two);
+
+    assert_eq!(history.undo(), Some(plan.moves[1]));
+    assert_eq!(history.undo(), Some(plan.moves[0]));
+    assert_eq!(history.undo(), None);
+    assert_eq!(history.stacks(), &plan.stacks);
+    assert_eq!(history.redo(), Some(plan.moves[0]));
+    assert_eq!(history.redo(), Some(plan.moves[1]));
+    assert_eq!(history.stacks(), &after_two);
+
+    history.undo();
+    history.apply(plan.moves[1]).unwrap();
+    assert_eq!(history.redo(), None);
+    assert_eq!(history.done(), &plan.moves[..2]);
+}
@@ -0,0 +1,31 @@
+//! Markers of the datastream, for any window size.
+
+use common::{Error, Solution};
+use dec_6::{find_marker, Day6, START_OF_MESSAGE, START_OF_PACKET};
+
+#[test]
+fn markers_of_any_size() {
+    let stream = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    assert_eq!(find_marker(stream, 0), Some(0));
+    assert_eq!(find_marker(stream, 1), Some(1));
+    assert_eq!(find_marker(stream, 2), Some(2));
+    assert_eq!(find_marker(stream, START_OF_PACKET), Some(7));
+    assert_eq!(find_marker(stream, START_OF_MESSAGE), Some(19));
+    assert_eq!(find_marker(stream, stream.len() + 1), None);
+}
+
+#[test]
+fn streams_without_marker() {
+    assert_eq!(find_marker("", START_OF_PACKET), None);
+    assert_eq!(find_marker("abc", START_OF_PACKET), None);
+    assert_eq!(find_marker("abcabcabcabc", START_OF_PACKET), None);
+    assert_eq!(find_marker("abcabcabcd", START_OF_PACKET), Some(10));
+}
+
+#[test]
+fn streams_are_ascii() {
+    assert_eq!(
+        Day6::parse("abcé\n").err(),
+        Some(Error::unexpected(1, 4, "an ASCII character", "é"))
+    );
+}
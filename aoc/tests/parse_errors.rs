@@ -0,0 +1,94 @@
+//! Malformed inputs are reported where they go wrong instead of panicking.
+
+use common::{Answer, Error, Solution};
+
+#[test]
+fn dec_4_missing_separator() {
+    assert_eq!(
+        dec_4::Day4::parse("2-4,6-8\n2-3;4-5\n").err(),
+        Some(Error::unexpected(2, 8, "`,`", ""))
+    );
+}
+
+#[test]
+fn dec_9_unknown_direction() {
+    assert_eq!(
+        dec_9::Day9::parse("R 4\nQ 2\n").err(),
+        Some(Error::unexpected(2, 1, "R, L, U or D", "Q"))
+    );
+}
+
+#[test]
+fn dec_10_bad_value() {
+    assert_eq!(
+        dec_10::Day10::parse("noop\naddx 1x\n").err(),
+        Some(Error::unexpected(2, 6, "a value to add", "1x"))
+    );
+}
+
+#[test]
+fn dec_10_program_shorter_than_220_cycles() {
+    for program in ["", "noop\naddx 3\naddx -5\n"] {
+        let solved = common::run::<dec_10::Day10>(program, Some(1)).unwrap();
+        assert_eq!(solved[0].answer, Answer::Unsolved);
+    }
+}
+
+#[test]
+fn dec_11_divisor_of_zero() {
+    let notes =
+        include_str!("../../dec-11/input-test.txt").replace("divisible by 23", "divisible by 0");
+    assert_eq!(
+        dec_11::Day11::parse(&notes).err(),
+        Some(Error::unexpected(4, 22, "a positive divisor", "0"))
+    );
+}
+
+#[test]
+fn dec_11_throw_to_no_monkey() {
+    let notes = include_str!("../../dec-11/input-test.txt");
+    assert_eq!(
+        dec_11::Day11::parse(&notes.replacen("throw to monkey 3", "throw to monkey 4", 1)).err(),
+        Some(Error::unexpected(
+            6,
+            31,
+            "a monkey from 0 to 3 other than 0",
+            "4"
+        ))
+    );
+    assert_eq!(
+        dec_11::Day11::parse(&notes.replacen("throw to monkey 2", "throw to monkey 0", 1)).err(),
+        Some(Error::unexpected(
+            5,
+            30,
+            "a monkey from 0 to 3 other than 0",
+            "0"
+        ))
+    );
+}
+
+#[test]
+fn dec_13_unpaired_packet() {
+    assert_eq!(
+        dec_13::Day13::parse("[1]\n[2]\n\n[3]\n").err(),
+        Some(Error::end_of_input(5, "the second packet of the pair"))
+    );
+}
+
+#[test]
+fn dec_15_fixed_offsets_are_gone() {
+    let line = "Sensor at x=2, y=18: closest beacon is at x=-2, z=15";
+    assert_eq!(
+        dec_15::Day15::parse(line).err(),
+        Some(Error::unexpected(1, 49, "`y=`", "z=15"))
+    );
+}
+
+#[test]
+fn error_names_the_file() {
+    let err = Error::unexpected(3, 7, "a number", "x").in_file("dec-1/input.txt");
+    assert_eq!(
+        err.to_string(),
+        "dec-1/input.txt:3:7: expected a number, found `x`"
+    );
+}
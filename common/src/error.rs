@@ -0,0 +1,99 @@
+use std::fmt;
+
+/// Why the puzzle input could not be parsed.
+///
+/// Parsers only see the text of the input, the runner names the file with
+/// [`Error::in_file`] before reporting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Something else than `expected` was found at `line` and `column`, both from 1.
+    Unexpected {
+        file: Option<String>,
+        line: usize,
+        column: usize,
+        expected: String,
+        found: String,
+    },
+    /// The input ends at `line` while `expected` is still missing.
+    EndOfInput {
+        file: Option<String>,
+        line: usize,
+        expected: String,
+    },
+}
+
+impl Error {
+    pub fn unexpected(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Error {
+        Error::Unexpected {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    pub fn end_of_input(line: usize, expected: impl Into<String>) -> Error {
+        Error::EndOfInput {
+            file: None,
+            line,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn in_file(mut self, name: impl Into<String>) -> Error {
+        match &mut self {
+            Error::Unexpected { file, .. } | Error::EndOfInput { file, .. } => {
+                *file = Some(name.into())
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = match self {
+            Error::Unexpected { file, .. } | Error::EndOfInput { file, .. } => {
+                file.as_deref().unwrap_or("<input>")
+            }
+        };
+        match self {
+            Error::Unexpected {
+                line,
+                column,
+                expected,
+                found,
+                ..
+            } => {
+                if found.is_empty() {
+                    write!(
+                        f,
+                        "{}:{}:{}: expected {}, found end of line",
+                        file, line, column, expected
+                    )
+                } else {
+                    write!(
+                        f,
+                        "{}:{}:{}: expected {}, found `{}`",
+                        file, line, column, expected, found
+                    )
+                }
+            }
+            Error::EndOfInput { line, expected, .. } => {
+                write!(
+                    f,
+                    "{}:{}: expected {}, found end of input",
+                    file, line, expected
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,178 @@
+//! Two dimensional grids, stored densely in rows or sparsely by position.
+//!
+//! Both backends implement [`Grid`], which gives the neighbours, rays and
+//! rendering shared by the days working on a map.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+
+mod dense;
+mod sparse;
+
+pub use dense::DenseGrid;
+pub use sparse::SparseGrid;
+
+/// A position on a grid, `x` grows to the right and `y` downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    pub fn step(self, dir: Dir) -> Point {
+        self + dir.delta()
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The four points sharing a side with this one.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Dir::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The eight points sharing a side or a corner with this one.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+            .filter(|delta| *delta != Point::default())
+            .map(move |delta| self + delta)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::Up => Point::new(0, -1),
+            Dir::Down => Point::new(0, 1),
+            Dir::Left => Point::new(-1, 0),
+            Dir::Right => Point::new(1, 0),
+        }
+    }
+}
+
+/// Rectangle of a grid, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    /// The bounds of a single point.
+    pub fn at(p: Point) -> Bounds {
+        Bounds { min: p, max: p }
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+
+    /// Grows the bounds until they contain `p`.
+    pub fn include(&mut self, p: Point) {
+        self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    /// Points of the rectangle, row by row.
+    pub fn points(self) -> impl Iterator<Item = Point> {
+        (self.min.y..=self.max.y)
+            .flat_map(move |y| (self.min.x..=self.max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+pub trait Grid {
+    type Cell;
+
+    fn get(&self, p: Point) -> Option<&Self::Cell>;
+
+    /// Where the cells are, `None` for an empty grid.
+    fn bounds(&self) -> Option<Bounds>;
+
+    fn in_bounds(&self, p: Point) -> bool {
+        self.bounds().is_some_and(|bounds| bounds.contains(p))
+    }
+
+    /// Neighbours of `p` sharing a side with it, within the bounds.
+    fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> {
+        p.neighbours4().filter(|n| self.in_bounds(*n))
+    }
+
+    /// Neighbours of `p` sharing a side or a corner with it, within the bounds.
+    fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> {
+        p.neighbours8().filter(|n| self.in_bounds(*n))
+    }
+
+    /// Points met going from `from`, excluded, towards `dir` until leaving the bounds.
+    fn ray(&self, from: Point, dir: Dir) -> impl Iterator<Item = Point> {
+        let bounds = self.bounds();
+        std::iter::successors(Some(from.step(dir)), move |p| Some(p.step(dir)))
+            .take_while(move |p| bounds.is_some_and(|bounds| bounds.contains(*p)))
+    }
+
+    /// Draws `area` with one character per cell, rows separated by newlines.
+    fn render_area(&self, area: Bounds, glyph: impl Fn(Option<&Self::Cell>) -> char) -> String {
+        (area.min.y..=area.max.y)
+            .map(|y| {
+                (area.min.x..=area.max.x)
+                    .map(|x| glyph(self.get(Point::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Draws the whole grid, see [`Grid::render_area`].
+    fn render(&self, glyph: impl Fn(Option<&Self::Cell>) -> char) -> String {
+        match self.bounds() {
+            Some(bounds) => self.render_area(bounds, glyph),
+            None => String::new(),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::ops::{Index, IndexMut};
+
+use super::{Bounds, Grid, Point};
+use crate::Error;
+
+/// A rectangle of cells stored row by row, the top-left one at `0,0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> DenseGrid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> DenseGrid<T>
+    where
+        T: Clone,
+    {
+        DenseGrid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Reads one cell per character, `cell` rejects the characters that are not `expected`.
+    /// Every row must be as wide as the first one.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<DenseGrid<T>, Error> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for l in crate::lines(input) {
+            let width = *width.get_or_insert(l.text.chars().count());
+            let mut row_width = 0;
+            for (i, c) in l.text.char_indices() {
+                if row_width == width {
+                    return Err(l.error(&l.text[i..], "end of line"));
+                }
+                cells.push(cell(c).ok_or_else(|| l.error(&l.text[i..i + c.len_utf8()], expected))?);
+                row_width += 1;
+            }
+            if row_width < width {
+                return Err(l.error(&l.text[l.text.len()..], expected));
+            }
+            height += 1;
+        }
+        Ok(DenseGrid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        let (x, y) = (usize::try_from(p.x).ok()?, usize::try_from(p.y).ok()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new((i % width) as i32, (i / width) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The first point, row by row, whose cell matches.
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(Point, &T) -> U) -> DenseGrid<U> {
+        DenseGrid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(p, cell)| f(p, cell)).collect(),
+        }
+    }
+}
+
+impl<T> Grid for DenseGrid<T> {
+    type Cell = T;
+
+    fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (!self.cells.is_empty()).then(|| Bounds {
+            min: Point::default(),
+            max: Point::new(self.width as i32 - 1, self.height as i32 - 1),
+        })
+    }
+}
+
+impl<T> Index<Point> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| {
+            panic!(
+                "{} is outside of the {}x{} grid",
+                p, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for DenseGrid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{} is outside of the {}x{} grid", p, width, height))
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use super::{Bounds, Grid, Point};
+
+/// Cells stored by position, for grids without fixed edges. The bounds grow
+/// with the cells inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    pub fn insert(&mut self, p: Point, cell: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(p),
+            None => self.bounds = Some(Bounds::at(p)),
+        }
+        self.cells.insert(p, cell)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(p, cell)| (*p, cell))
+    }
+}
+
+impl<T> Grid for SparseGrid<T> {
+    type Cell = T;
+
+    fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+}
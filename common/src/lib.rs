@@ -0,0 +1,79 @@
+mod answer;
+mod error;
+pub mod grid;
+mod line;
+
+pub use answer::Answer;
+pub use error::Error;
+pub use line::{lines, Line};
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// A day of the calendar. The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// Answer of a part and the time spent solving it, parsing excluded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Parses `input` and solves the requested part, or both when `part` is `None`.
+pub fn run<S: Solution>(input: &str, part: Option<u8>) -> Result<Vec<Solved>, Error> {
+    let parsed = S::parse(input)?;
+    let solve = |part, solver: fn(&S::Input) -> Answer| {
+        let start = Instant::now();
+        let answer = solver(&parsed);
+        Solved {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        }
+    };
+    let mut solved = vec![];
+    if part != Some(2) {
+        solved.push(solve(1, S::part1));
+    }
+    if part != Some(1) {
+        solved.push(solve(2, S::part2));
+    }
+    Ok(solved)
+}
+
+/// Time spent on each step of a run.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Parses `input` and solves both parts, timing each step.
+pub fn time<S: Solution>(input: &str) -> Result<Timings, Error> {
+    let start = Instant::now();
+    let parsed = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part1(&parsed));
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part2(&parsed));
+    let part2 = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use crate::Error;
+
+/// A line of the puzzle input, which knows its number to report errors.
+///
+/// The `part` given to the methods is a slice of `text`, its column is found
+/// from where it starts in the line.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// Column, from 1, where `part` starts.
+    pub fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        offset.min(self.text.len()) + 1
+    }
+
+    /// Reports the first word of `part` where `expected` should be.
+    pub fn error(&self, part: &str, expected: impl Into<String>) -> Error {
+        let part = part.trim_start();
+        let found = part.split_whitespace().next().unwrap_or("");
+        Error::unexpected(self.number, self.column(part), expected, found)
+    }
+
+    pub fn parse<T: FromStr>(&self, part: &'a str, expected: &str) -> Result<T, Error> {
+        part.trim().parse().map_err(|_| self.error(part, expected))
+    }
+
+    pub fn split_once(&self, part: &'a str, separator: &str) -> Result<(&'a str, &'a str), Error> {
+        part.split_once(separator)
+            .ok_or_else(|| self.error(&part[part.len()..], format!("`{}`", separator)))
+    }
+
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, Error> {
+        part.strip_prefix(prefix)
+            .ok_or_else(|| self.error(part, format!("`{}`", prefix)))
+    }
+}
@@ -0,0 +1,47 @@
+use common::grid::{Bounds, DenseGrid, Dir, Grid, Point, SparseGrid};
+use common::Error;
+
+fn digits(input: &str) -> DenseGrid<u32> {
+    DenseGrid::parse(input, "a digit", |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn dense_rays_stop_at_the_edge() {
+    let grid = digits("123\n456\n789\n");
+    let centre = Point::new(1, 1);
+    let cells = |dir| grid.ray(centre, dir).map(|p| grid[p]).collect::<Vec<_>>();
+    assert_eq!(cells(Dir::Up), vec![2]);
+    assert_eq!(cells(Dir::Down), vec![8]);
+    assert_eq!(cells(Dir::Left), vec![4]);
+    assert_eq!(cells(Dir::Right), vec![6]);
+    assert_eq!(grid.ray(Point::new(0, 0), Dir::Left).count(), 0);
+}
+
+#[test]
+fn neighbours_stay_in_bounds() {
+    let grid = digits("123\n456\n789\n");
+    assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn dense_parse_rejects_ragged_rows() {
+    let err = DenseGrid::parse("123\n45\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(err, Error::unexpected(2, 3, "a digit", ""));
+}
+
+#[test]
+fn sparse_bounds_grow_with_cells() {
+    let mut grid = SparseGrid::new();
+    grid.insert(Point::new(500, 0), '+');
+    grid.insert(Point::new(498, 2), '#');
+    assert_eq!(
+        grid.bounds(),
+        Some(Bounds {
+            min: Point::new(498, 0),
+            max: Point::new(500, 2),
+        })
+    );
+    assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "..+\n...\n#..");
+}
@@ -0,0 +1,99 @@
+use common::{Answer, Error, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub mod report;
+
+/// Food carried by one elf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the input, from 0.
+    pub index: usize,
+    /// Calories of all the items.
+    pub total: i32,
+    /// Number of items.
+    pub items: usize,
+    /// Calories of the biggest item, 0 without items.
+    pub largest: i32,
+}
+
+/// The `n` elves carrying the most calories, most first. Elves carrying as much
+/// are ranked in input order.
+///
+/// Only the best `n` elves are kept in a heap while scanning, so this is
+/// O(elves * log n) whatever `n`.
+pub fn top_n(elves: &[Elf], n: usize) -> Vec<Elf> {
+    if n == 0 {
+        return vec![];
+    }
+    // Min-heap of the best elves seen, the weakest one on top to be evicted.
+    // Elves are found back by their position in `elves`, which may be any
+    // part of the input.
+    let mut best = BinaryHeap::with_capacity(n + 1);
+    for (position, elf) in elves.iter().enumerate() {
+        best.push(Reverse((elf.total, Reverse(elf.index), position)));
+        if best.len() > n {
+            best.pop();
+        }
+    }
+    best.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((_, _, position))| elves[position].clone())
+        .collect()
+}
+
+// Elves are separated by an empty line, two in a row make an elf without
+// items. A separator ending the input does not.
+fn parse(input: &str) -> Result<Vec<Elf>, Error> {
+    let mut elves = vec![];
+    let mut elf = Elf { index: 0, total: 0, items: 0, largest: 0 };
+    // Whether a line follows the last separator.
+    let mut open = false;
+    for l in common::lines(input) {
+        let is_empty = l.text.is_empty();
+        if is_empty {
+            let index = elf.index + 1;
+            elves.push(elf);
+            elf = Elf { index, total: 0, items: 0, largest: 0 };
+            open = false;
+        }
+        else {
+            let i: i32 = l.parse(l.text, "calories")?;
+            elf.total += i;
+            elf.items += 1;
+            elf.largest = elf.largest.max(i);
+            open = true;
+        }
+    }
+    if open {
+        elves.push(elf);
+    }
+    Ok(elves)
+}
+
+fn part_one(elves: &[Elf]) -> Option<i32> {
+    top_n(elves, 1).first().map(|elf| elf.total)
+}
+
+fn part_two(elves: &[Elf]) -> i32 {
+    top_n(elves, 3).iter().map(|elf| elf.total).sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    // Food carried by each elf, in input order.
+    type Input = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(elves: &Self::Input) -> Answer {
+        part_one(elves).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(elves: &Self::Input) -> Answer {
+        part_two(elves).into()
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt::Write;
+
+use crate::Elf;
+
+/// Percentiles of the totals given in the report.
+pub const PERCENTILES: [u8; 5] = [25, 50, 75, 90, 99];
+
+/// Elves whose totals fall in `from..=to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: i32,
+    pub to: i32,
+    pub count: usize,
+}
+
+/// Descriptive statistics over the calories carried by the elves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+    /// Elves without a single item.
+    pub empty: usize,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    /// Nearest-rank percentiles of the totals, as `(percentile, total)`.
+    pub percentiles: Vec<(u8, i32)>,
+    /// Calories of the biggest item carried by any elf.
+    pub largest_item: Option<i32>,
+    /// Totals split in buckets of the same width, from the least to the most.
+    pub histogram: Vec<Bucket>,
+}
+
+impl Report {
+    /// Statistics over `elves`, with at most `buckets` histogram buckets.
+    pub fn new(elves: &[Elf], buckets: usize) -> Report {
+        let mut totals: Vec<i32> = elves.iter().map(|elf| elf.total).collect();
+        totals.sort_unstable();
+        let n = totals.len();
+
+        let mean = (n > 0).then(|| totals.iter().map(|&t| t as f64).sum::<f64>() / n as f64);
+        let median = (n > 0).then(|| {
+            if n % 2 == 1 {
+                totals[n / 2] as f64
+            } else {
+                (totals[n / 2 - 1] as f64 + totals[n / 2] as f64) / 2.0
+            }
+        });
+        let percentiles = if n == 0 {
+            vec![]
+        } else {
+            PERCENTILES
+                .iter()
+                .map(|&p| {
+                    let rank = (p as usize * n).div_ceil(100).max(1);
+                    (p, totals[rank - 1])
+                })
+                .collect()
+        };
+
+        Report {
+            elves: n,
+            empty: elves.iter().filter(|elf| elf.items == 0).count(),
+            mean,
+            median,
+            percentiles,
+            largest_item: elves
+                .iter()
+                .filter(|elf| elf.items > 0)
+                .map(|elf| elf.largest)
+                .max(),
+            histogram: histogram(&totals, buckets),
+        }
+    }
+
+    /// The report as aligned `statistic  value` lines, then the histogram as bars.
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        for (name, value) in self.statistics() {
+            let _ = writeln!(out, "{:<14} {:>10}", name, value);
+        }
+        let widest = self.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        if widest > 0 {
+            let _ = writeln!(out, "\nhistogram of totals");
+        }
+        for bucket in &self.histogram {
+            let bar = "#".repeat((bucket.count * 40).div_ceil(widest));
+            let range = format!("{}-{}", bucket.from, bucket.to);
+            let row = format!("{:>14} {:>6} {}", range, bucket.count, bar);
+            let _ = writeln!(out, "{}", row.trim_end());
+        }
+        out
+    }
+
+    /// The report as `statistic,value` rows, one row per histogram bucket
+    /// named `histogram <from>-<to>`.
+    pub fn csv(&self) -> String {
+        let mut out = String::from("statistic,value\n");
+        for (name, value) in self.statistics() {
+            let _ = writeln!(out, "{},{}", name, value);
+        }
+        for bucket in &self.histogram {
+            let _ = writeln!(
+                out,
+                "histogram {}-{},{}",
+                bucket.from, bucket.to, bucket.count
+            );
+        }
+        out
+    }
+
+    fn statistics(&self) -> Vec<(String, String)> {
+        let or_none = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+        let mut rows = vec![
+            ("elves".to_string(), self.elves.to_string()),
+            ("empty".to_string(), self.empty.to_string()),
+            (
+                "mean".to_string(),
+                or_none(self.mean.map(|m| format!("{:.1}", m))),
+            ),
+            (
+                "median".to_string(),
+                or_none(self.median.map(|m| format!("{:.1}", m))),
+            ),
+        ];
+        for (p, total) in &self.percentiles {
+            rows.push((format!("p{}", p), total.to_string()));
+        }
+        rows.push((
+            "largest item".to_string(),
+            or_none(self.largest_item.map(|i| i.to_string())),
+        ));
+        rows
+    }
+}
+
+fn histogram(sorted: &[i32], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return vec![];
+    };
+    if buckets == 0 {
+        return vec![];
+    }
+    let (min, max) = (min as i64, max as i64);
+    let span = (max - min + 1) as u64;
+    let width = span.div_ceil(buckets as u64);
+    let mut histogram: Vec<Bucket> = (0..span.div_ceil(width) as i64)
+        .map(|i| Bucket {
+            from: (min + i * width as i64) as i32,
+            to: (min + (i + 1) * width as i64 - 1).min(max) as i32,
+            count: 0,
+        })
+        .collect();
+    for &total in sorted {
+        histogram[((total as i64 - min) as u64 / width) as usize].count += 1;
+    }
+    histogram
+}
@@ -202,27 +202,33 @@ Find the signal strength during the 20th, 60th, 100th, 140th, 180th, and 220th c
 */
 
 
-use std::fs::File;
-use std::io::BufReader;
-use std::io::prelude::*;
-use regex::Regex;
-fn part_one() ->  std::io::Result<()> {
-    let addx_regex = Regex::new(r"addx (-?[0-9]+)").unwrap();
-    let file = File::open("input-test2.txt")?;
-    let reader = BufReader::new(file);
+use common::{Answer, Error, Solution};
+use log::{debug, info, trace};
+
+// `None` for a noop, the value to add otherwise.
+fn parse(input: &str) -> Result<Vec<Option<i32>>, Error> {
+    common::lines(input)
+        .map(|l| {
+            if l.text == "noop" {
+                return Ok(None)
+            }
+            let value = l.strip_prefix(l.text, "addx ").map_err(|_| l.error(l.text, "`noop` or `addx`"))?;
+            Ok(Some(l.parse::<i32>(value, "a value to add")?))
+        })
+        .collect()
+}
 
+// `None` when the program stops before the 220th cycle.
+fn part_one(program: &[Option<i32>]) -> Option<i32> {
     let mut cycles: Vec<i32> = vec![1];
 
-    for line in reader.lines() {
-        let l = line?;
-        if l == "noop" {
+    for instruction in program {
+        let Some(addx) = instruction else {
             let last_val = cycles.last().unwrap();
             cycles.push(*last_val);
             continue
-        }
-        let matches = addx_regex.captures(&l).unwrap();
-        let addx = matches.get(1).unwrap().as_str().parse::<i32>().unwrap();
-        let last_val = cycles.last().unwrap().clone();
+        };
+        let last_val = *cycles.last().unwrap();
         cycles.push(last_val);
         cycles.push(last_val + addx);
 
@@ -230,14 +236,17 @@ fn part_one() ->  std::io::Result<()> {
     }
     // 20th, 60th, 100th, 140th, 180th, and 220th
 
-    let signals = vec![(20, cycles[19]), (60, cycles[59]), (100, cycles[99]), (140, cycles[139]), (180, cycles[179]), (220, cycles[219])];
+    // X during cycle n is the value at the end of cycle n - 1.
+    let signals = [20, 60, 100, 140, 180, 220]
+        .iter()
+        .map(|&n| cycles.get(n as usize - 1).map(|&x| (n, x)))
+        .collect::<Option<Vec<(i32, i32)>>>()?;
     let strength: i32 = signals.iter().map(|(cycle_n, signal)| cycle_n * signal ).sum();
-    println!("signal {:?}", signals);
-    println!("strength {:?}", strength);
+    info!("signal {:?}", signals);
     // println!("20:{} 60:{} 100:{} 140:{} 180:{} 220:{}",  20* cycles[19] , 60*cycles[59] , cycles[99] , cycles[139] , cycles[179] , cycles[219]);
     // let strength = 20* cycles[19] +  60*cycles[59] *  100* cycles[99] +  140*cycles[139] + 180* cycles[179] + 220* cycles[219];
 
-   Ok(())
+    Some(strength)
 }
 
 /*
@@ -380,47 +389,34 @@ Render the image given by your program. What eight capital letters appear on you
 
 */
 
-fn draw_pixel(display: &mut Vec<Vec<&str>>, cycle_cnt: i32, sprite_position: i32) {
+fn draw_pixel(display: &mut [Vec<&str>], cycle_cnt: i32, sprite_position: i32) {
     if cycle_cnt >= 240 {
-        println!("STOP");
+        trace!("STOP");
         return
     }
-    println!(">>> cycle #{}", cycle_cnt+1);
+    trace!(">>> cycle #{}", cycle_cnt+1);
     let col = cycle_cnt % 40;
     let row =  cycle_cnt / 40;
-    println!("sprite_pos {} pixel #{}", sprite_position, cycle_cnt);
+    trace!("sprite_pos {} pixel #{}", sprite_position, cycle_cnt);
     if col == sprite_position +1 || col == sprite_position -1 || col == sprite_position {
         display[row as usize][col as usize] = "#";
     }
     else {
         display[row as usize][col as usize] = ".";
     }
-    println!("row {:?}", display[row as usize].join(""));
+    debug!("row {:?}", display[row as usize].join(""));
 }
 
-fn part_two() ->  std::io::Result<()> {
-    let addx_regex = Regex::new(r"addx (-?[0-9]+)").unwrap();
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
+fn part_two(program: &[Option<i32>]) -> String {
     // let mut cycles: Vec<i32> = vec![1];
     let mut display: Vec<Vec<&str>> = vec![vec!["_";40], vec!["_";40], vec!["_";40],vec!["_";40],vec!["_";40],vec!["_";40]];
 
     let mut cycle_cnt = 0;
     let mut sprite_position = 1;
 
-    for line in reader.lines() {
-        let l = line?;
-        if l == "noop" {
-            println!("___ noop");
-            // let last_val = cycles.last().unwrap().clone();
-            draw_pixel(&mut display, cycle_cnt, sprite_position);
-            // cycles.push(last_val);
-            cycle_cnt +=1;
-        }
-        else {
-            let matches = addx_regex.captures(&l).unwrap();
-            let addx = matches.get(1).unwrap().as_str().parse::<i32>().unwrap();
-            println!("___ addx {}", addx);
+    for instruction in program {
+        if let Some(addx) = *instruction {
+            trace!("___ addx {}", addx);
             // let last_val = cycles.last().unwrap().clone();
             //first cycle of addx
             // cycles.push(last_val);
@@ -435,18 +431,34 @@ fn part_two() ->  std::io::Result<()> {
             // cycles.push(last_val + addx);
 
         }
+        else {
+            trace!("___ noop");
+            // let last_val = cycles.last().unwrap().clone();
+            draw_pixel(&mut display, cycle_cnt, sprite_position);
+            // cycles.push(last_val);
+            cycle_cnt +=1;
+        }
 
         // println!("cycles {:?}", cycles);
     }
-    for v in display {
-        println!("{:?}", v.join(""));
-    }
-
-   Ok(())
+    display.iter().map(|v| v.join("")).collect::<Vec<String>>().join("\n")
 }
 
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Option<i32>>;
 
-fn main() {
-//    part_one();
-   part_two();
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        part_one(program).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    // The CRT image, the answer is the eight letters it shows.
+    fn part2(program: &Self::Input) -> Answer {
+        part_two(program).into()
+    }
 }
@@ -235,17 +235,18 @@ Figure out which monkeys to chase by counting how many items they inspect over 2
 What is the level of monkey business after 20 rounds of stuff-slinging simian shenanigans?
 
 */
-use std::io::prelude::*;
 use std::collections::VecDeque;
-#[derive(PartialEq)]
-enum Op {
-  ADD,
-  MUL,
-  SQUARE
+use common::{Answer, Error, Line, Solution};
+use log::{debug, info};
+#[derive(Clone, PartialEq)]
+pub enum Op {
+  Add,
+  Mul,
+  Square
 }
 
-#[derive(PartialEq)]
-struct Monkey {
+#[derive(Clone, PartialEq)]
+pub struct Monkey {
   val: i64,
   op: Op,
   test: i64,
@@ -255,18 +256,18 @@ struct Monkey {
 }
 
 impl Monkey {
-  fn func(self: &Self, old: i64) -> i64 {
-    if self.op == Op::ADD {
-      return old + self.val;
+  fn func(&self, old: i64) -> i64 {
+    if self.op == Op::Add {
+      old + self.val
     }
-    else if self.op == Op::MUL {
-      return old * self.val;
+    else if self.op == Op::Mul {
+      old * self.val
     }
-    else if self.op == Op::SQUARE {
-      return old * old;
+    else if self.op == Op::Square {
+      old * old
     }
     else {
-      return 0
+      0
     }
   }
 }
@@ -278,150 +279,91 @@ fn move_item_true(monkey_items: &mut VecDeque<i64>, item: i64) {
 fn move_item_false(monkey_items: &mut VecDeque<i64>, item: i64) {
   monkey_items.push_back(item);
 }
-fn part_one(num_rounds: i64) -> std::io::Result<()> {
-  // let mut monkeys: Vec<Monkey> = vec![
-  //   Monkey{
-  //     val: 19,
-  //     op: Op::MUL,
-  //     test: 23,
-  //     true_: 2,
-  //     false_: 3,
-  //     inspect_count: 0
-  //   },
-  //   Monkey{
-  //     val: 6,
-  //     op: Op::ADD,
-  //     test: 19,
-  //     true_: 2,
-  //     false_: 0,
-  //     inspect_count: 0
-  //   },
-
-  //   Monkey{
-  //     val: 0,
-  //     op: Op::SQUARE,
-  //     test: 13,
-  //     true_: 1,
-  //     false_: 3,
-  //     inspect_count: 0
-  //   },
-  //   Monkey{
-  //     val: 3,
-  //     op: Op::ADD,
-  //     test: 17,
-  //     true_: 0,
-  //     false_: 1,
-  //     inspect_count: 0
-  //   },
-  // ];
-
-  let mut monkeys: Vec<Monkey> = vec![
-    //0
-    Monkey{
-      val: 13,
-      op: Op::MUL,
-      test: 2,
-      true_: 5,
-      false_: 2,
-      inspect_count: 0
-    },
-    //1
-    Monkey{
-      val: 7,
-      op: Op::ADD,
-      test: 13,
-      true_: 4,
-      false_: 3,
-      inspect_count: 0
-    },
-    //2
-    Monkey{
-      val: 2,
-      op: Op::ADD,
-      test: 5,
-      true_: 5,
-      false_: 1,
-      inspect_count: 0
-    },
-    //3
-    Monkey{
-      val: 2,
-      op: Op::MUL,
-      test: 3,
-      true_: 6,
-      false_: 7,
-      inspect_count: 0
-    },
-    //4
-    Monkey{
-      val: 0,
-      op: Op::SQUARE,
-      test: 11,
-      true_: 7,
-      false_: 3,
-      inspect_count: 0
-    },
-    //5
-    Monkey{
-      val: 6,
-      op: Op::ADD,
-      test: 17,
-      true_: 4,
-      false_: 1,
-      inspect_count: 0
-    },
-     //6
-     Monkey{
-      val: 1,
-      op: Op::ADD,
-      test: 7,
-      true_: 0,
-      false_: 2,
-      inspect_count: 0
-    },
-     //7
-     Monkey{
-      val: 8,
-      op: Op::ADD,
-      test: 19,
-      true_: 6,
-      false_: 0,
+// Divisor of `Test: divisible by 13`, never 0.
+fn divisor(line: &Line) -> Result<i64, Error> {
+  let number = line.strip_prefix(line.text.trim_start(), "Test: divisible by ")?;
+  match line.parse(number, "a number")? {
+    d if d > 0 => Ok(d),
+    _ => Err(line.error(number, "a positive divisor")),
+  }
+}
+
+// Monkey an item is thrown to, one of the `count` monkeys other than monkey `i`.
+fn target(line: &Line, prefix: &str, i: usize, count: usize) -> Result<usize, Error> {
+  let number = line.strip_prefix(line.text.trim_start(), prefix)?;
+  match line.parse(number, "a monkey")? {
+    n if n < count && n != i => Ok(n),
+    _ => Err(line.error(number, format!("a monkey from 0 to {} other than {}", count - 1, i))),
+  }
+}
+
+fn parse(input: &str) -> Result<(Vec<Monkey>, Vec<VecDeque<i64>>), Error> {
+  let mut monkeys: Vec<Monkey> = vec![];
+  let mut monkey_items: Vec<VecDeque<i64>> = vec![];
+  let lines: Vec<Line> = common::lines(input).filter(|l| !l.text.trim().is_empty()).collect();
+  let count = lines.len() / 6;
+  for (i, notes) in lines.chunks(6).enumerate() {
+    if notes.len() < 6 {
+      return Err(Error::end_of_input(input.lines().count() + 1, "the six notes of a monkey"));
+    }
+    notes[0].strip_prefix(notes[0].text, "Monkey ")?;
+
+    let items = notes[1].strip_prefix(notes[1].text.trim_start(), "Starting items:")?;
+    monkey_items.push(
+      items.split(',')
+        .filter(|i| !i.trim().is_empty())
+        .map(|i| notes[1].parse(i, "a worry level"))
+        .collect::<Result<_, _>>()?
+    );
+
+    let operation = notes[2].strip_prefix(notes[2].text.trim_start(), "Operation: new = old ")?;
+    let (op, val) = match operation.split_once(' ') {
+      Some(("*", "old")) => (Op::Square, 0),
+      Some(("*", v)) => (Op::Mul, notes[2].parse(v, "a number or `old`")?),
+      Some(("+", v)) => (Op::Add, notes[2].parse(v, "a number")?),
+      _ => return Err(notes[2].error(operation, "`+` or `*`")),
+    };
+    monkeys.push(Monkey{
+      val,
+      op,
+      test: divisor(&notes[3])?,
+      true_: target(&notes[4], "If true: throw to monkey ", i, count)?,
+      false_: target(&notes[5], "If false: throw to monkey ", i, count)?,
       inspect_count: 0
-    },
-  ];
-  // let mut monkey_items: Vec<VecDeque<i64>> = vec![[79,98].into(),[54, 65, 75, 74].into(), [79, 60, 97].into(), [74].into()];
-  let mut monkey_items: Vec<VecDeque<i64>> = vec![
-    [91, 54, 70, 61, 64, 64, 60, 85].into(),
-    [82].into(),
-    [84, 93, 70].into(),
-    [ 78, 56, 85, 93].into(),
-    [ 64, 57, 81, 95, 52, 71, 58].into(),
-    [ 58, 71, 96, 58, 68, 90].into(),
-    [ 56, 99, 89, 97, 81].into(),
-    [68, 72].into(),
-  ];
+    });
+  }
+  Ok((monkeys, monkey_items))
+}
+
+// Without relief the worry level is no longer divided by three after each inspection.
+fn monkey_business(notes: &(Vec<Monkey>, Vec<VecDeque<i64>>), num_rounds: i64, relief: bool) -> i64 {
+  let mut monkeys = notes.0.clone();
+  let mut monkey_items = notes.1.clone();
   let divisible_product: i64 = monkeys.iter().map(|m| m.test).collect::<Vec<_>>().iter().product();
-  println!("divisible product {}", divisible_product);
+  info!("divisible product {}", divisible_product);
   for round in  0..num_rounds {
     // println!("---- round #{}", round);
     // println!("monkey_items {:?}", monkey_items);
     if round % 1000 == 0 || round == 20 {
      let counts = monkeys.iter().map(|m| m.inspect_count).collect::<Vec<_>>();
-      println!("round {} counts {:?}", round, counts);
+      debug!("round {} counts {:?}", round, counts);
     }
 
     for i in 0..monkeys.len() {
       // println!("___monkey #{}", i);
-      let mut current_monkey = &mut monkeys[i];
+      let current_monkey = &mut monkeys[i];
       for _ in 0..monkey_items[i].len() {
         current_monkey.inspect_count +=1;
         let item = monkey_items[i].pop_front().unwrap();
         // println!("item {}", item);
-        // part_one
-        // let worry_level = current_monkey.func(item) / 3 as i64;
-
-        // part_two
-        let worry_level = current_monkey.func(item) % divisible_product as i64 ;
+        let worry_level = if relief {
+          // part_one
+          current_monkey.func(item) / 3
+        }
+        else {
+          // part_two
+          current_monkey.func(item) % divisible_product
+        };
 
         if worry_level % current_monkey.test == 0 {
           move_item_true(&mut monkey_items[current_monkey.true_], worry_level);
@@ -434,11 +376,8 @@ fn part_one(num_rounds: i64) -> std::io::Result<()> {
   }
   let mut counts = monkeys.iter().map(|m| m.inspect_count).collect::<Vec<_>>();
   counts.sort_by(|a, b| b.partial_cmp(a).unwrap());
-  println!("counts {:?}", counts);
-  let level: i64 = counts.iter().take(2).product();
-  println!("level {}", level);
-
-  Ok(())
+  info!("counts {:?}", counts);
+  counts.iter().take(2).product()
 }
 
 /*
@@ -533,7 +472,21 @@ Starting again from the initial state in your puzzle input, what is the level of
 
 */
 
-fn main() {
-    part_one(20);
-    part_one(10000);
+pub struct Day11;
+
+impl Solution for Day11 {
+  // Monkeys and the items each one holds.
+  type Input = (Vec<Monkey>, Vec<VecDeque<i64>>);
+
+  fn parse(input: &str) -> Result<Self::Input, Error> {
+    parse(input)
+  }
+
+  fn part1(notes: &Self::Input) -> Answer {
+    monkey_business(notes, 20, true).into()
   }
+
+  fn part2(notes: &Self::Input) -> Answer {
+    monkey_business(notes, 10000, false).into()
+  }
+}
@@ -0,0 +1,347 @@
+/*
+--- Day 12: Hill Climbing Algorithm ---
+
+You try contacting the Elves using your handheld device, but the river you're following must be too low to get a decent signal.
+
+You ask the device for a heightmap of the surrounding area (your puzzle input).
+The heightmap shows the local area from above broken into a grid; the elevation of each square of the grid is given by a single lowercase letter, where a is the lowest elevation, b is the next-lowest, and so on up to the highest elevation, z.
+
+Also included on the heightmap are marks for your current position (S) and the location that should get the best signal (E).
+Your current position (S) has elevation a, and the location that should get the best signal (E) has elevation z.
+
+You'd like to reach E, but to save energy, you should do it in as few steps as possible.
+During each step, you can move exactly one square up, down, left, or right. To avoid needing to get out your climbing gear,
+the elevation of the destination square can be at most one higher than the elevation of your current square;
+that is, if your current elevation is m, you could step to elevation n, but not to elevation o.
+(This also means that the elevation of the destination square can be much lower than the elevation of your current square.)
+
+For example:
+
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+
+Here, you start in the top-left corner; your goal is near the middle.
+You could start by moving down or right, but eventually you'll need to head toward the e at the bottom.
+From there, you can spiral around to the goal:
+
+v..v<<<<
+>v.vv<<^
+.>vv>E^^
+..v>>>^^
+..>>>>>^
+
+In the above diagram, the symbols indicate whether the path exits each square moving up (^), down (v), left (<), or right (>).
+The location that should get the best signal is still E, and . marks unvisited squares.
+
+This path reaches the goal in 31 steps, the fewest possible.
+
+What is the fewest steps required to move from your current position to the location that should get the best signal?
+
+*/
+use std::hash::Hash;
+use std::collections::HashMap;
+use priority_queue::PriorityQueue;
+use common::grid::{DenseGrid, Grid, Point};
+use common::{Answer, Error, Solution};
+use log::{debug, info};
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Location {
+    v: i32,
+    p: Point,
+    repr: char,
+    is_goal: bool
+}
+
+#[derive(Eq, Debug, PartialEq)]
+struct Graph {
+    edges_map: HashMap<Location, Vec<Location>>,
+    weigths: HashMap<Location, i32>
+}
+
+struct PathSolution {
+    #[allow(dead_code)]
+    cost_so_far: HashMap<Location, i32>,
+    came_from: HashMap<Location, Location>,
+    path: Vec<Location>,
+    found: bool
+}
+
+impl PathSolution {
+    #[allow(dead_code)]
+    fn show_explored(&self, grid: &DenseGrid<Location>, start: Location, end: Location) {
+
+        // SHOW explored
+        for row in grid.rows() {
+            let mut row_vec = vec![];
+            for col in row {
+                if start.p == col.p {
+                    row_vec.push(String::from("S"));
+                }
+                else if end.p == col.p {
+                    row_vec.push(String::from("E"));
+                }
+                else {
+                    match self.cost_so_far.get(col) {
+                        Some(&i) => {row_vec.push(i.to_string())},
+                        None => {
+                            row_vec.push(String::from("."));
+                        },
+                    }
+                }
+            }
+            let str = row_vec.join(",").clone();
+            debug!("{:?}", str);
+        }
+    }
+    fn show_came_from(&self, grid: &DenseGrid<Location>, start: Location, end: Location) {
+        // SHOW CAME FROM
+        debug!("{}", grid.render(|col| match col {
+            Some(col) if start.p == col.p => 'S',
+            Some(col) if end.p == col.p => 'E',
+            Some(col) if self.came_from.contains_key(col) => 'X',
+            _ => '.',
+        }));
+    }
+
+    fn compute_path(&self, start: Location, end: Location) -> Vec<Location> {
+        // COMPUTE PATH
+        let mut current: Location = end;
+        let mut path: Vec<Location> = vec![];
+        while current != start {
+            path.push(current);
+            current = *self.came_from.get(&current).unwrap_or_else(|| panic!("Ohoh: {:?}", &current))
+        }
+        path.push(start);
+        path.reverse();
+        info!("PathSolution len {}", path.len() -1);// discard end
+        path
+    }
+    fn show_path(&self, grid: &DenseGrid<Location>, start: Location, end: Location) {
+        // SHOW PATH ON GRID
+        debug!("num_rows {}", grid.height());
+        debug!("num_cols {}", grid.width());
+        let on_path: HashMap<Point, char> = self.path.iter().map(|l| (l.p, l.repr)).collect();
+        debug!("{}", grid.render(|col| match col {
+            Some(col) if start.p == col.p => 'S',
+            Some(col) if end.p == col.p => 'E',
+            Some(col) => on_path.get(&col.p).copied().unwrap_or('.'),
+            None => '.',
+        }));
+    }
+}
+
+impl Graph {
+    fn cost(&self, _from: Location, to: Location) -> i32 {
+        match self.weigths.get(&to) {
+            Some(&_i) => 1,
+            None => panic!("Weigth not found {:?}", to)
+        }
+    }
+    #[allow(dead_code)]
+    fn heuristic(&self, a: Location, b: Location) -> i32 {
+        a.p.manhattan(b.p)
+    }
+
+
+    fn new(grid: &DenseGrid<Location>) -> Graph {
+        let mut edges_map: HashMap<Location, Vec<Location>>  = HashMap::new();
+        let mut weigths: HashMap<Location,i32>  = HashMap::new();
+        for (p, &location) in grid.iter() {
+            weigths.insert(location, 1);
+            for neigh in grid.neighbours4(p) {
+                let diff: i32 = grid[neigh].v - location.v;
+                let is_connected = diff <= 1; // can go down, one up or flat
+                if is_connected {
+                    edges_map.entry(location).or_default().push(grid[neigh]);
+                }
+            }
+            // println!("{}: {} [{:?}]", p, location.repr, edges_map.get(&location))
+        }
+
+        Graph{ edges_map, weigths}
+    }
+
+    fn neighbors(&self, id: Location) -> Vec<Location> {
+        match self.edges_map.get(&id) {
+            Some(neigh) => neigh.to_vec(),
+            None => vec![]
+        }
+    }
+    fn bfs(&self, start: Location, target: Location) -> PathSolution {
+        let mut frontier = PriorityQueue::new();
+        frontier.push(start, 0);
+
+        let mut came_from = HashMap::<Location, Location>::new();
+
+        let mut cost_so_far = HashMap::<Location, i32>::new();
+        cost_so_far.insert(start, 0);
+        let mut found_solution = false;
+        while !frontier.is_empty() {
+            let (current, _) = frontier.pop().unwrap();
+            if current == target {
+                found_solution = true;
+                // println!("Found!");
+            //     break
+            }
+
+            // println!("\n-----------{:?}-----------", current);
+            for neigh in self.neighbors(current) {
+                let current_cost = cost_so_far.get(&current).unwrap();
+
+                let new_cost = current_cost + self.cost(current, neigh);
+                let (neigh_is_visited, neigh_cost_so_far) = match cost_so_far.get(&neigh) {
+                    Some(c) => (true, *c) ,
+                    _ => (false, 9999),
+                };
+                if !neigh_is_visited {
+                    cost_so_far.insert(neigh, new_cost);
+                    // println!("not visited {:?} {}", neigh, new_cost);
+                    // if current.repr >= 'n' {
+                    // println!("new path {:?} -> {:?} {:?}", current, neigh, new_cost);
+                    // }
+                    let _priority = new_cost; //+ self.heuristic(neigh, target);
+                    // frontier.push(neigh.clone(), priority);
+                    frontier.push(neigh, 1);
+                    came_from.insert(neigh, current);
+                }
+                else if new_cost < neigh_cost_so_far {
+                    cost_so_far.insert(neigh, new_cost);
+                    let _priority = new_cost; //+ self.heuristic(neigh, target);
+                    // frontier.push(neigh.clone(), priority);
+                    frontier.push(neigh, 1);
+                    came_from.insert(neigh, current);
+                }
+                else {
+                    // println!("ignored {:?}", neigh)
+                }
+
+            }
+        }
+
+        // cost_so_far.insert(target, 0);
+        let mut sol = PathSolution{came_from, cost_so_far, path: vec![], found: found_solution};
+        if found_solution {
+            let path = sol.compute_path(start, target);
+            sol.path = path;
+        }
+        sol
+    }
+}
+
+
+fn parse(input: &str) -> Result<DenseGrid<char>, Error> {
+    let char_grid = DenseGrid::parse(input, "a lowercase elevation, `S` or `E`", |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+    for marker in ['S', 'E'] {
+        if char_grid.find(|c| *c == marker).is_none() {
+            return Err(Error::end_of_input(char_grid.height() + 1, format!("`{}`", marker)));
+        }
+    }
+    Ok(char_grid)
+}
+
+// The start `S` has elevation a and the goal `E` elevation z.
+fn locations(char_grid: &DenseGrid<char>) -> DenseGrid<Location> {
+    char_grid.map(|p, &repr| {
+        let v = match repr {
+            'S' => 1,
+            'E' => 26,
+            c => (c as u8 - b'a' + 1) as i32,
+        };
+        Location{p, v, repr, is_goal: repr == 'E'}
+    })
+}
+
+fn part_one(char_grid: &DenseGrid<char>) -> Option<usize> {
+    let grid = locations(char_grid);
+    let start = grid[grid.find(|l| l.repr == 'S')?];
+    let end = grid[grid.find(|l| l.is_goal)?];
+    let graph = Graph::new(&grid);
+
+    let path = graph.bfs(start, end);
+    path.show_came_from(&grid, start, end);
+    path.show_path(&grid, start, end);
+
+
+    // println!("path {:?}", path.iter().map(|l| l.repr.to_string() + " " + &l.p.to_string()).collect::<Vec<String>>());
+    if !path.found {
+        return None
+    }
+    Some(path.path.len() - 1)
+}
+
+
+/*
+--- Part Two ---
+As you walk up the hill, you suspect that the Elves will want to turn this into a hiking trail. The beginning isn't very scenic, though; perhaps you can find a better starting point.
+
+To maximize exercise while hiking, the trail should start as low as possible: elevation a.
+The goal is still the square marked E.
+However, the trail should still be direct, taking the fewest steps to reach its goal. So, you'll need to find the shortest path from any square at elevation a to the square marked E.
+
+Again consider the example from above:
+
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+
+Now, there are six choices for starting position (five marked a, plus the square marked S that counts as being at elevation a). If you start at the bottom-left square, you can reach the goal most quickly:
+
+...v<<<<
+...vv<<^
+...v>E^^
+.>v>>>^^
+>^>>>>>^
+
+This path reaches the goal in only 29 steps, the fewest possible.
+
+What is the fewest steps required to move starting from any square with elevation a to the location that should get the best signal?
+*/
+
+fn part_two(char_grid: &DenseGrid<char>) -> Option<usize> {
+    let grid = locations(char_grid);
+    let possible_starts: Vec<Location> = grid.iter().map(|(_, l)| *l).filter(|l| l.v == 1).collect();
+    let end = grid[grid.find(|l| l.is_goal)?];
+
+    let mut min_len = None;
+    for start in possible_starts {
+        let graph = Graph::new(&grid);
+        let path: PathSolution = graph.bfs(start, end);
+        if path.found {
+            // println!("Start {:?}", start);
+            let l = path.path.len()-1;
+            if min_len.is_none_or(|min| l < min) {
+                min_len = Some(l);
+                info!("shorter path of {} steps from {}", l, start.p);
+            }
+            // path.show_path(&grid, start, end);
+        }
+    }
+    // path.show_came_from(&grid, start, end);
+    min_len
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    // Heightmap, one letter per square.
+    type Input = DenseGrid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(char_grid: &Self::Input) -> Answer {
+        part_one(char_grid).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(char_grid: &Self::Input) -> Answer {
+        part_two(char_grid).map_or(Answer::Unsolved, Answer::from)
+    }
+}
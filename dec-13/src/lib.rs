@@ -124,112 +124,107 @@ Determine which pairs of packets are already in the right order. What is the sum
 
 */
 
-use std::fs::File;
-use std::hash::Hash;
-use std::io::BufReader;
-use std::io::prelude::*;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::{CharIndices, FromStr};
+use common::{Answer, Error, Line, Solution};
 
-
-use std::str::FromStr;
-#[derive(Debug)]
-enum NestedArray {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NestedArray {
     Int(i32),
     Array(Vec<NestedArray>),
 }
 
 
+// Byte offset in the packet, or `usize::MAX` past its end, and what was expected there.
+type PacketError = (usize, &'static str);
+
 impl FromStr for NestedArray {
-  type Err = &'static str;
+  type Err = PacketError;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-      let mut s = s.chars();
-      let mut array = Vec::new();
+      let mut s = s.trim_end().char_indices().peekable();
       // println!("STR -> {:?}", s);
-      while let Some(c) = s.next() {
-          // println!("C-> {}", c);
-          if c == '[' {
-              // Parse array
-              let element = parse_element(&mut s)?;
-              array.push(element);
-          } else if c.is_digit(10) {
-              // Parse integer
-              let mut i = c.to_string();
-              while let Some(c) = s.next() {
-                  if !c.is_digit(10) {
-                  //     s.prev();
+      let element = parse_element(&mut s)?;
+      if let Some((i, _)) = s.next() {
+          return Err((i, "end of packet"));
+      }
+      Ok(element)
+  }
+}
+
+fn parse_element(s: &mut Peekable<CharIndices>) -> Result<NestedArray, PacketError> {
+  match s.next() {
+      Some((_, '[')) => {
+          // Parse array
+          let mut array = Vec::new();
+          loop {
+              match s.peek() {
+                  Some((_, ']')) => {
+                      s.next();
                       break;
                   }
-                  i.push(c);
+                  Some((_, ',')) => {
+                      s.next();
+                  }
+                  Some(_) => array.push(parse_element(s)?),
+                  None => return Err((usize::MAX, "`]`")),
               }
-              let element = i.parse().map(NestedArray::Int).unwrap();
-              array.push(element);
-          } else if c.is_whitespace() || c == ',' {
-              // Ignore whitespace
-          } else {
-              return Err("unexpected character");
           }
-      }
-      if array.len() == 1 {
-          Ok(array.into_iter().next().unwrap())
-      } else {
           Ok(NestedArray::Array(array))
       }
-  }
-}
-
-fn parse_element(s: &mut std::str::Chars) -> Result<NestedArray, &'static str> {
-  let mut array = Vec::new();
-  while let Some(c) = s.next() {
-      if c == '[' {
-          // Parse array
-          let element = parse_element(s)?;
-          array.push(element);
-      } else if c.is_digit(10) {
-          // Parse integer
+      Some((start, c)) if c.is_ascii_digit() => {
+          // Parse integer, the character following it is left for the caller
           let mut i = c.to_string();
-          while let Some(c) = s.next() {
-              if !c.is_digit(10) {
-              //     s.prev();
-                  break;
-              }
+          while let Some((_, c)) = s.next_if(|(_, c)| c.is_ascii_digit()) {
               i.push(c);
           }
-          let element = i.parse().map(NestedArray::Int).unwrap();
-          array.push(element);
-      } else if c.is_whitespace() || c == ',' {
-          // Ignore whitespace
-      } else if c == ']' {
-          break;
-      } else {
-          return Err("unexpected character");
+          i.parse().map(NestedArray::Int).map_err(|_| (start, "a number"))
       }
+      Some((i, _)) => Err((i, "`[` or a number")),
+      None => Err((usize::MAX, "`[` or a number")),
   }
-  if array.len() == 1 {
-      Ok(array.into_iter().next().unwrap())
-  } else {
-      Ok(NestedArray::Array(array))
+}
+
+impl Ord for NestedArray {
+  fn cmp(&self, other: &Self) -> Ordering {
+      match (self, other) {
+          (NestedArray::Int(left), NestedArray::Int(right)) => left.cmp(right),
+          // Lists compare item by item, the shortest one first when they share a prefix.
+          (NestedArray::Array(left), NestedArray::Array(right)) => left.cmp(right),
+          (NestedArray::Int(left), NestedArray::Array(_)) => NestedArray::Array(vec![NestedArray::Int(*left)]).cmp(other),
+          (NestedArray::Array(_), NestedArray::Int(right)) => self.cmp(&NestedArray::Array(vec![NestedArray::Int(*right)])),
+      }
   }
 }
 
-fn part_one() -> std::io::Result<()>{
-    let file = File::open("input-test.txt")?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-    let mut in_order: Vec<i32> = vec![];
-    let mut idx: i32 = 1;
-    while let (Some(left), Some(right), Some(empty)) = (lines.next(), lines.next(), lines.next()) {
-        let left_arr: NestedArray = left.unwrap().parse().unwrap();
-        let right_arr: NestedArray = right.unwrap().parse().unwrap();
-
-        match left_arr {
-          NestedArray::Int(x) => println!("{}", x),
-          NestedArray::Array(elements) => {
-            for element in elements {
-              iterate_array(element);
-            }
-          }
-        }
+impl PartialOrd for NestedArray {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+      Some(self.cmp(other))
+  }
+}
+
+fn parse_packet(l: &Line) -> Result<NestedArray, Error> {
+    l.text.parse().map_err(|(i, expected): PacketError| l.error(&l.text[i.min(l.text.len())..], expected))
+}
+
+fn parse(input: &str) -> Result<Vec<(NestedArray, NestedArray)>, Error> {
+    let mut pairs = vec![];
+    let mut lines = common::lines(input).filter(|l| !l.text.is_empty());
+    while let Some(left) = lines.next() {
+        let left = parse_packet(&left)?;
+        let Some(right) = lines.next() else {
+            return Err(Error::end_of_input(input.lines().count() + 1, "the second packet of the pair"));
+        };
+        pairs.push((left, parse_packet(&right)?));
+    }
+    Ok(pairs)
+}
+
+fn part_one(pairs: &[(NestedArray, NestedArray)]) -> usize {
+    let mut in_order: Vec<usize> = vec![];
+    for (idx, (left, right)) in pairs.iter().enumerate() {
+
         // If both values are integers, the lower integer should come first. If the left integer is lower than the right integer, the inputs are in the right order.
         // If the left integer is higher than the right integer, the inputs are not in the right order.
         // Otherwise, the inputs are the same integer; continue checking the next part of the input.
@@ -244,16 +239,28 @@ fn part_one() -> std::io::Result<()>{
         // For example, if comparing [0,0,0] and 2, convert the right value to [2] (a list containing 2); the result is then found by instead comparing [0,0,0] and [2].
 
         // println!("l1 {:?} l2 {:?}", left, right);
-        let ordered = false;
+        let ordered = left < right;
         if ordered {
-          in_order.push(idx);
+          in_order.push(idx + 1);
         }
-        idx +=1;
     }
-    // println!("sum {}", in_order.iter().sum());
-    Ok(())
+    in_order.iter().sum()
 }
 
-fn main() {
-    part_one();
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(NestedArray, NestedArray)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        part_one(pairs).into()
+    }
+
+    fn part2(_pairs: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
 }
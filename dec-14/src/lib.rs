@@ -142,154 +142,136 @@ Using your scan, simulate the falling sand. How many units of sand come to rest
 
 */
 
-use std::fs::File;
-use std::io::BufReader;
-use std::io::prelude::*;
-use std::collections::HashMap;
 use itertools::Itertools;
+use common::grid::{Bounds, Grid, Point, SparseGrid};
+use common::{Answer, Error, Solution};
+use log::{debug, log, log_enabled, trace, Level};
 
-#[derive(PartialEq)]
-enum Material  {
+#[derive(Clone, PartialEq)]
+pub enum Material  {
     Sand,
     Rock,
     Floor
 }
 
-fn build_rock_path(prev: &str, next: &str, rock_path: &mut HashMap<(i32,i32), Material>, abyss:&mut (i32, i32)) {
-    let mut s_prev = prev.split(",");
-    let mut s_next = next.split(",");
-    let (x_prev,y_prev): (i32, i32) = (s_prev.next().unwrap().parse().unwrap(), s_prev.next().unwrap().parse().unwrap());
-    let (x_next,y_next): (i32, i32) = (s_next.next().unwrap().parse().unwrap(), s_next.next().unwrap().parse().unwrap());
-    // println!("{},{} {},{}", x_prev, y_prev, x_next, y_next);
-
-    let x_seq = if x_prev < x_next {x_prev..x_next+1} else {x_next..x_prev+1};
-    let y_seq = if y_prev < y_next {y_prev..y_next+1} else {y_next..y_prev+1};
-    for x in x_seq.clone() {
-        for y in y_seq.clone() {
-            // println!("insert -> {},{}", x, y);
-            rock_path.insert((x,y), Material::Rock);
-            // Update the lowest and left-most point
-            if abyss.0 > x {
-                abyss.0 = x;
-
-            }
-            if abyss.1 < y {
-                abyss.1 = y;
-            }
+fn build_rock_path(prev: Point, next: Point, rock_path: &mut SparseGrid<Material>, abyss: &mut Point) {
+    // println!("{} {}", prev, next);
+    let mut segment = Bounds::at(prev);
+    segment.include(next);
+    for p in segment.points() {
+        // println!("insert -> {}", p);
+        rock_path.insert(p, Material::Rock);
+        // Update the lowest and left-most point
+        if abyss.x > p.x {
+            abyss.x = p.x;
+
+        }
+        if abyss.y < p.y {
+            abyss.y = p.y;
         }
     }
 }
 
-fn settle_sand( start: (i32, i32), rock_path:&HashMap<(i32,i32), Material>, abyss: (i32, i32)) -> (i32, i32) {
-    let positions = vec![(start.0, start.1+ 1), (start.0-1, start.1+1), (start.0+1, start.1+1)];
+fn settle_sand(start: Point, rock_path: &SparseGrid<Material>, abyss: Point) -> Point {
+    let positions = vec![Point::new(start.x, start.y + 1), Point::new(start.x - 1, start.y + 1), Point::new(start.x + 1, start.y + 1)];
 
     for position in positions {
-        let is_blocked = match rock_path.get(&position) {
-            Some(_) => true,
-            None => false
-        };
+        let is_blocked = rock_path.contains(position);
         // The sand cannot settle at the next location, try the next position
         if is_blocked {
             continue;
         }
-        if  position.0 <= abyss.0 || position.1 >= abyss.1 {
+        if  position.x <= abyss.x || position.y >= abyss.y {
             // println!("falling into abyss");
             return position;
         }
-        // println!("falling to {:?}", position);
+        // println!("falling to {}", position);
         return settle_sand(position, rock_path, abyss);
     }
     // the sand is settled. below, left-down and right-down are all blocked
-    // println!("idle pos {},{}", start.0, start.1);
-    return start
+    // println!("idle pos {}", start);
+    start
 }
 
-fn drop_sand(start: (i32, i32),  rock_path:&mut HashMap<(i32,i32), Material>, abyss: (i32, i32)) ->bool {
+fn drop_sand(start: Point, rock_path: &mut SparseGrid<Material>, abyss: Point) -> bool {
     let idle_pos =  settle_sand(start, rock_path, abyss);
     rock_path.insert(idle_pos, Material::Sand);
-    let into_abyss = idle_pos.0 <= abyss.0 || idle_pos.1 >= abyss.1;
-    println!("into abyss {}\n", into_abyss);
+    let into_abyss = idle_pos.x <= abyss.x || idle_pos.y >= abyss.y;
+    trace!("into abyss {}", into_abyss);
     into_abyss
 
 }
 
-fn drop_sand_v2(start: (i32, i32),  rock_path:&mut HashMap<(i32,i32), Material>, abyss: (i32, i32)) ->bool {
-    let idle_pos =  settle_sand(start, rock_path, (-1,99999999));
+fn drop_sand_v2(start: Point, rock_path: &mut SparseGrid<Material>, _abyss: Point) -> bool {
+    let idle_pos =  settle_sand(start, rock_path, Point::new(-1, 99999999));
     rock_path.insert(idle_pos, Material::Sand);
-    let into_abyss = idle_pos.0 == 500 && idle_pos.1 == 0;
-    println!("into abyss {}\n", into_abyss);
+    let into_abyss = idle_pos == SOURCE;
+    trace!("into abyss {}", into_abyss);
     into_abyss
 
 }
 
 
 
-fn plot_grid(rock_path: &mut HashMap<(i32, i32), Material>) {
-    let mut bx = 500;
-    let mut by = 0;
-    let mut tx = 500;
-    let ty = 0;
-
-    for (x,y) in rock_path.keys() {
-        if x < &bx {
-            bx = *x;
-        }
-        if x > &tx {
-            tx = *x;
-        }
-        if y > &by {
-            by = *y;
-        }
+fn plot_grid(rock_path: &SparseGrid<Material>, level: Level) {
+    if !log_enabled!(level) {
+        return;
     }
-    println!("New grid bounds {},{} {},{}", bx,by, tx,ty );
-    for y in ty..by+1 {
-        let mut row = vec![];
-        for x in bx..tx+1 {
-            let symbol = match rock_path.get(&(x,y)) {
-                Some(Material::Sand) => "o",
-                Some(Material::Rock) => "#",
-                Some(Material::Floor) => "&",
-                None => "."
-            };
-            // println!("{},{} -> {}", x,y, symbol);
-            row.push(symbol);
-        }
-        println!("{} {:?}", y, row.into_iter().join(""));
+    let mut bounds = Bounds::at(SOURCE);
+    if let Some(scan) = rock_path.bounds() {
+        bounds.include(scan.min);
+        bounds.include(scan.max);
     }
+    log!(level, "New grid bounds {} {}", bounds.min, bounds.max);
+    log!(level, "{}", rock_path.render_area(bounds, |material| match material {
+        Some(Material::Sand) => 'o',
+        Some(Material::Rock) => '#',
+        Some(Material::Floor) => '&',
+        None => '.'
+    }));
 
 }
 
 
-fn part_one() -> std::io::Result<()> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-    let mut rock_path: HashMap<(i32, i32), Material> = HashMap::new();
-    let mut abyss = (500,0);
-    while let Some(line) = lines.next() {
-        let l = line?;
-        let split = l.split(" -> ").collect::<Vec<&str>>();
-        let iter = Itertools::tuple_windows(split.iter());
+// Rock of the scan and the lowest, left-most point below which sand falls into the abyss.
+pub type Scan = (SparseGrid<Material>, Point);
+
+// Sand pours into the cave from this point.
+const SOURCE: Point = Point::new(500, 0);
+
+fn parse(input: &str) -> Result<Scan, Error> {
+    let mut rock_path = SparseGrid::new();
+    let mut abyss = SOURCE;
+    for l in common::lines(input) {
+        let points = l.text.split(" -> ").map(|point| {
+            let (x, y) = l.split_once(point, ",")?;
+            Ok(Point::new(l.parse(x, "a distance to the right")?, l.parse(y, "a distance down")?))
+        }).collect::<Result<Vec<Point>, Error>>()?;
+        let iter = Itertools::tuple_windows(points.into_iter());
         for (prev, next) in iter {
-            // println!("\n------\npath: {:?} -> {:?}", prev, next);
+            // println!("\n------\npath: {} -> {}", prev, next);
             build_rock_path(prev, next, &mut rock_path, &mut abyss);
-            // plot_grid(&mut rock_path);
+            // plot_grid(&rock_path, Level::Trace);
         }
     }
+    Ok((rock_path, abyss))
+}
+
+fn part_one(scan: &Scan) -> i32 {
+    let (mut rock_path, abyss) = scan.clone();
     let mut counter = 0;
-    plot_grid(&mut rock_path);
-    while !drop_sand((500, 0), &mut rock_path, abyss) {
-        plot_grid(&mut rock_path);
-        println!("-------- round {}-----------\n", counter);
-        println!("Abyss {},{}", abyss.0, abyss.1);
+    plot_grid(&rock_path, Level::Debug);
+    while !drop_sand(SOURCE, &mut rock_path, abyss) {
+        plot_grid(&rock_path, Level::Trace);
+        debug!("-------- round {}-----------", counter);
+        trace!("Abyss {}", abyss);
 
         counter +=1 ;
         if counter > 3000 {
             break
         }
     }
-    println!("Counter {}", counter);
-    Ok(())
+    counter
 }
 
 /*
@@ -340,96 +322,96 @@ In the example above, the situation finally looks like this after 93 units of sa
 Using your scan, simulate the falling sand until the source of the sand becomes blocked. How many units of sand come to rest?
 
 */
-fn build_floor(y_offset:i32, rock_path: &mut HashMap<(i32, i32), Material>) {
-    let mut bx = 500;
-    let mut by = 0;
-    let mut tx = 500;
-    let ty = 0;
+fn build_floor(_y_offset:i32, rock_path: &mut SparseGrid<Material>) {
+    let mut bx = SOURCE.x;
+    let mut by = SOURCE.y;
+    let mut tx = SOURCE.x;
     let mut y_floor = -1;
     let mut bx_floor = 99999999;
     let mut tx_floor = -1;
-    for ((x,y), material) in rock_path.into_iter() {
-        if x < &bx && *material != Material::Floor {
-            bx = *x;
+    for (Point{x, y}, material) in rock_path.iter() {
+        if x < bx && *material != Material::Floor {
+            bx = x;
         }
-        if x > &tx && *material != Material::Floor{
-            tx = *x;
+        if x > tx && *material != Material::Floor{
+            tx = x;
         }
-        if y > &by && *material != Material::Floor {
-            by = *y;
+        if y > by && *material != Material::Floor {
+            by = y;
         }
-        if x >= &tx_floor && *material == Material::Floor {
-            tx_floor = *x;
+        if x >= tx_floor && *material == Material::Floor {
+            tx_floor = x;
         }
-        if x <= &bx_floor && *material == Material::Floor {
-            bx_floor = *x;
+        if x <= bx_floor && *material == Material::Floor {
+            bx_floor = x;
         }
-        if y >= &y_floor && *material == Material::Floor {
-            y_floor = *y;
+        if y >= y_floor && *material == Material::Floor {
+            y_floor = y;
         }
     }
 
     // initial case without floor;
-    println!("floor {} {} {}", bx_floor, tx_floor, y_floor );
+    trace!("floor {} {} {}", bx_floor, tx_floor, y_floor );
     if y_floor == -1 {
         y_floor = by+2;
         bx_floor = bx;
         tx_floor = tx;
         for x in bx_floor-20..tx_floor+20 {
-            rock_path.insert((x, y_floor), Material::Floor);
+            rock_path.insert(Point::new(x, y_floor), Material::Floor);
         }
     }
     else {
-        println!("GROW FLOOR {} {} {} {}", bx, bx_floor, tx, tx_floor);
+        trace!("GROW FLOOR {} {} {} {}", bx, bx_floor, tx, tx_floor);
         //grow floor
         if bx <=  bx_floor+3 {
-            rock_path.insert((bx-1, y_floor), Material::Floor);
-            rock_path.insert((bx-2, y_floor), Material::Floor);
+            rock_path.insert(Point::new(bx-1, y_floor), Material::Floor);
+            rock_path.insert(Point::new(bx-2, y_floor), Material::Floor);
         }
         if tx >= tx_floor - 3 {
-            rock_path.insert((tx+1, y_floor), Material::Floor);
-            rock_path.insert((tx+2, y_floor), Material::Floor);
+            rock_path.insert(Point::new(tx+1, y_floor), Material::Floor);
+            rock_path.insert(Point::new(tx+2, y_floor), Material::Floor);
         }
     }
 
 
 
 }
-fn part_two() -> std::io::Result<()> {
-    let file = File::open("input-test.txt")?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-    let mut rock_path: HashMap<(i32, i32), Material> = HashMap::new();
-    let mut abyss = (500,0);
-    while let Some(line) = lines.next() {
-        let l = line?;
-        let split = l.split(" -> ").collect::<Vec<&str>>();
-        let iter = Itertools::tuple_windows(split.iter());
-        for (prev, next) in iter {
-            // println!("\n------\npath: {:?} -> {:?}", prev, next);
-            build_rock_path(prev, next, &mut rock_path, &mut abyss);
-        }
-    }
+fn part_two(scan: &Scan) -> i32 {
+    let (mut rock_path, abyss) = scan.clone();
     build_floor(2, &mut rock_path);
-    plot_grid(&mut rock_path);
+    plot_grid(&rock_path, Level::Debug);
 
     let mut counter = 0;
-    while !drop_sand_v2((500, 0), &mut rock_path, abyss) {
+    while !drop_sand_v2(SOURCE, &mut rock_path, abyss) {
         build_floor(2, &mut rock_path);
-        // plot_grid(&mut rock_path);
-        println!("-------- round {}-----------\n", counter);
-        println!("Abyss {},{}", abyss.0, abyss.1);
+        // plot_grid(&rock_path, Level::Trace);
+        debug!("-------- round {}-----------", counter);
+        trace!("Abyss {}", abyss);
 
         counter +=1 ;
         if counter > 30000 {
             break
         }
     }
-    plot_grid(&mut rock_path);
-    println!("Counter {}", counter);
-    Ok(())
+    plot_grid(&rock_path, Level::Debug);
+    // The last unit of sand rests on the source and counts too.
+    counter + 1
 }
-fn main() {
-    // part_one();
-    part_two();
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Scan;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(scan: &Self::Input) -> Answer {
+        part_one(scan).into()
+    }
+
+    fn part2(scan: &Self::Input) -> Answer {
+        part_two(scan).into()
+    }
 }
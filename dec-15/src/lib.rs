@@ -118,12 +118,11 @@ Consult the report from the sensors you just deployed. In the row where y=200000
 
 */
 
-use std::fs::File;
-use std::io::BufReader;
-use std::io::prelude::*;
 use std::collections::HashSet;
 use std::fmt;
 use std::cmp;
+use common::{Answer, Error, Line, Solution};
+use log::debug;
 
 #[derive(Copy, Clone)]
 struct Point {
@@ -138,21 +137,23 @@ impl fmt::Debug for Point {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Pair {
+pub struct Pair {
     beacon: Point,
     sensor: Point
 }
 impl Pair {
-    fn radius(self: &Self) -> i32 {
-        return (self.beacon.x - self.sensor.x).abs() + (self.beacon.y - self.sensor.y).abs()
+    fn radius(&self) -> i32 {
+        (self.beacon.x - self.sensor.x).abs() + (self.beacon.y - self.sensor.y).abs()
     }
-    fn delta_y(self: &Self) -> i32 {
-        return (self.beacon.y - self.sensor.y).abs()
+    #[allow(dead_code)]
+    fn delta_y(&self) -> i32 {
+        (self.beacon.y - self.sensor.y).abs()
     }
-    fn delta_x(self: &Self) -> i32 {
-        return (self.beacon.x - self.sensor.x).abs()
+    #[allow(dead_code)]
+    fn delta_x(&self) -> i32 {
+        (self.beacon.x - self.sensor.x).abs()
     }
-    fn covers(self: &Self, y: i32, (min, max): (i32, i32)) ->Vec<i32> {
+    fn covers(&self, y: i32, (min, max): (i32, i32)) ->Vec<i32> {
         let mut coverage = vec![];
         let radius = self.radius();
         let distance =  (self.sensor.y - y).abs();
@@ -172,72 +173,67 @@ impl Pair {
             coverage = (start_x..end_x+1).collect();
         }
         // println!("{:?}",self.radius());
-        return coverage
+        coverage
     }
 
-    fn anti_covers(self: &Self, y: i32, (min, max): (i32, i32)) -> (i32, i32) {
-        // let mut coverage = vec![];
+    // Section of the line `y` covered by the sensor, clamped to `(min, max)`.
+    fn anti_covers(&self, y: i32, (min, max): (i32, i32)) -> Option<(i32, i32)> {
         let radius = self.radius();
         let distance =  (self.sensor.y - y).abs();
-        let range_on_line = (cmp::max(0, radius - distance)).abs();
-        if distance <= radius {
-            // let range_on_line = radius - distance;
-            // let delta_x = self.delta_y();
-            let mut start_x = self.sensor.x - range_on_line;
-            let mut end_x = self.sensor.x + range_on_line;
-            if start_x < min {
-                start_x = min;
-            }
-
-            if end_x > max {
-                end_x = max;
-            }
-            return (start_x, end_x)
-            // let start: Vec<i32> = (min..start_x+1).collect();
-            // let end: Vec<i32> = (end_x..max+1).collect();
-            // coverage.extend(start);
-            // coverage.extend(end);
-            // coverage = (start_x..end_x+1).collect()
+        if distance > radius {
+            return None
         }
-        return (min, max)
-        // println!("{:?}",self.radius());
-        // return coverage
+        let range_on_line = radius - distance;
+        let start_x = cmp::max(min, self.sensor.x - range_on_line);
+        let end_x = cmp::min(max, self.sensor.x + range_on_line);
+        Some((start_x, end_x))
     }
 }
 
-fn get_pairs(filename: &str) -> Vec<Pair> {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-    let lines = reader.lines();
-    let mut pairs: Vec<Pair> = vec![];
-    for line in lines {
-        let l = line.unwrap();
-        let mut split = l.split(":");
-
-        let sensor_data =  &split.next().unwrap().to_string()[10..];
-        let mut sensor_split = sensor_data.split(",");
-        let sensor_x:i32 = sensor_split.next().unwrap().to_string()[2..].parse().unwrap();
-        let sensor_y:i32 = sensor_split.next().unwrap().to_string()[3..].parse().unwrap();
-        let sensor = Point{x: sensor_x, y: sensor_y};
-        // sensors.push(sensor);
-
-        let beacon_data =  &split.next().unwrap().to_string()[22..];
-        let mut beacon_split = beacon_data.split(",");
-        let beacon_x: i32 = beacon_split.next().unwrap().to_string()[2..].parse().unwrap();
-        let beacon_y:i32 = beacon_split.next().unwrap().to_string()[3..].parse().unwrap();
-        let beacon = Point{x: beacon_x, y: beacon_y};
-        // beacons.push(beacon);
+// A position written as `x=2, y=18`.
+fn get_point(l: &Line, data: &str) -> Result<Point, Error> {
+    let (x, y) = l.split_once(data, ", ")?;
+    let x = l.parse(l.strip_prefix(x, "x=")?, "a number")?;
+    let y = l.parse(l.strip_prefix(y, "y=")?, "a number")?;
+    Ok(Point{x, y})
+}
 
+// One pair per line, after the header if there is one.
+fn get_pairs(input: &str) -> Result<Vec<Pair>, Error> {
+    let mut pairs: Vec<Pair> = vec![];
+    for l in common::lines(input).skip_while(|l| l.number == 1 && l.text.starts_with("row=")) {
+        let (sensor_data, beacon_data) = l.split_once(l.text, ": ")?;
+        let sensor = get_point(&l, l.strip_prefix(sensor_data, "Sensor at ")?)?;
+        let beacon = get_point(&l, l.strip_prefix(beacon_data, "closest beacon is at ")?)?;
         pairs.push(Pair{beacon, sensor});
-
     }
-    pairs
+    Ok(pairs)
+}
 
+/// The sensors and beacons of the report, with the row of part one and the
+/// largest coordinate of the search area of part two.
+pub struct Report {
+    pub pairs: Vec<Pair>,
+    pub row: i32,
+    pub area: i32,
 }
 
-fn part_one(filename: &str, target_line: i32) -> std::io::Result<()> {
+// The puzzle asks about row 2000000 and coordinates up to 4000000, while its
+// example uses 10 and 20. Another input gives its own with a first line like
+// `row=10, area=20`.
+fn get_report(input: &str) -> Result<Report, Error> {
+    let Some(first) = common::lines(input).next().filter(|l| l.text.starts_with("row=")) else {
+        return Ok(Report { pairs: get_pairs(input)?, row: 2000000, area: 4000000 });
+    };
+    let (row, area) = first.split_once(first.text, ", ")?;
+    let row = first.parse(first.strip_prefix(row, "row=")?, "a row")?;
+    let area = first.parse(first.strip_prefix(area, "area=")?, "a coordinate")?;
+    let pairs = get_pairs(input)?;
+    Ok(Report { pairs, row, area })
+}
+
+fn part_one(pairs: &[Pair], target_line: i32) -> usize {
     let mut set_on_target_line: HashSet<i32> = HashSet::new();
-    let pairs: Vec<Pair> = get_pairs(filename);
     // println!("target_line {} min {} max {}", target_line);
     let mut covers = HashSet::new();
     for pair in pairs {
@@ -248,7 +244,7 @@ fn part_one(filename: &str, target_line: i32) -> std::io::Result<()> {
         if pair.beacon.y == target_line {
             set_on_target_line.insert(pair.beacon.x);
         }
-        let cover = pair.covers(target_line, (std::i32::MIN, std::i32::MAX));
+        let cover = pair.covers(target_line, (i32::MIN, i32::MAX));
         let cover_set:HashSet<i32> = HashSet::from_iter(cover.iter().cloned());
         covers.extend(cover_set);
     }
@@ -256,8 +252,7 @@ fn part_one(filename: &str, target_line: i32) -> std::io::Result<()> {
     let result = &covers - &set_on_target_line;
     // let mut arr = result.into_iter().collect::<Vec<i32>>();
     // arr.sort();
-    println!("{}", result.len());
-    Ok(())
+    result.len()
 }
 
 /*
@@ -277,47 +272,47 @@ there is only a single position that could have a beacon: x=14, y=11. The tuning
 Find the only possible position for the distress beacon. What is its tuning frequency?
 */
 
-fn compute_tuning_frequency(x:i32, y:i32) -> i32 {
-    x* 4000000 + y
+fn compute_tuning_frequency(x:i32, y:i32) -> i64 {
+    x as i64 * 4000000 + y as i64
 }
 
-fn part_two(filename: &str, search_space: i32) -> std::io::Result<()> {
-    // let mut candidates: HashSet<i32> = HashSet::new();
-    let line: HashSet<i32> = HashSet::from_iter(0..search_space+1);
-    let pairs: Vec<Pair> = get_pairs(filename);
+fn part_two(pairs: &[Pair], search_space: i32) -> Option<i64> {
     for y in 0..search_space+1 {
-        let mut left = 0;
-        let mut right = search_space;
-        println!("{}/{}", y, search_space+1);
-        let pairs: Vec<Pair> = get_pairs(filename);
-        let mut covers: HashSet<i32> = HashSet::new();
-        for pair in pairs {
-            let (min, max) = pair.anti_covers(y, (0, search_space+1));
-            if min > left {
-                left = min;
-            }
-            if max < right {
-                right = max;
+        let mut covers: Vec<(i32, i32)> = pairs
+            .iter()
+            .filter_map(|pair| pair.anti_covers(y, (0, search_space)))
+            .collect();
+        covers.sort();
+        // Sweep the sections from the left, the beacon is in the first gap.
+        let mut x = 0;
+        for (min, max) in covers {
+            if min > x {
+                break
             }
-            // let cover_set:HashSet<i32> = HashSet::from_iter(cover);
-            // covers.extend(cover_set);
+            x = cmp::max(x, max + 1);
         }
-        println!("y:{} {} {}", y, left, right);
-        // let candidates:HashSet<_> = line.difference(&covers).collect();
-        if left == right {
-            println!("x: {:?} y:{}", left, y);
-            break
+        if x <= search_space {
+            debug!("x: {:?} y:{}", x, y);
+            return Some(compute_tuning_frequency(x, y))
         }
-        // println!("y {:?} covers {:?} candidates {:?}", y, candidates, covers);
+    }
+    None
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Report;
 
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        get_report(input)
     }
 
-    Ok(())
-}
+    fn part1(report: &Self::Input) -> Answer {
+        part_one(&report.pairs, report.row).into()
+    }
 
-fn main() {
-    // part_one("input.txt", 2000000);
-    // part_one("input-test.txt",10);
-    part_two("input-test.txt", 20);
-    // part_two("input.txt", 4000000);
+    fn part2(report: &Self::Input) -> Answer {
+        part_two(&report.pairs, report.area).map_or(Answer::Unsolved, Answer::from)
+    }
 }
@@ -0,0 +1,195 @@
+//! What a strategy guide is worth, round by round, and how far it is from the
+//! best possible play.
+
+use std::fmt::Write;
+
+use common::Error;
+use log::trace;
+
+use crate::rules::{Play, Rules};
+use crate::{Column, Outcome};
+
+/// The guide followed with its second column read one way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reading {
+    pub column: Column,
+    pub plays: Vec<Play>,
+    pub scores: Vec<i32>,
+    pub score: i32,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Reading {
+    fn new(rules: &Rules, column: Column, plays: Vec<Play>) -> Reading {
+        let mut score = 0;
+        let scores = plays
+            .iter()
+            .map(|&play| {
+                let tmp_pts = rules.score(play);
+                score += tmp_pts;
+                trace!("{:?} {:?} {} {}", column, play, tmp_pts, score);
+                tmp_pts
+            })
+            .collect();
+        let (wins, draws, losses) = tally(rules, &plays);
+        Reading {
+            column,
+            plays,
+            scores,
+            score,
+            wins,
+            draws,
+            losses,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self.column {
+            Column::Shape => "shape",
+            Column::Outcome => "outcome",
+        }
+    }
+}
+
+/// Wins, draws and losses of `plays`.
+fn tally(rules: &Rules, plays: &[Play]) -> (usize, usize, usize) {
+    let (mut wins, mut draws, mut losses) = (0, 0, 0);
+    for play in plays {
+        match rules.outcome(play.mine, play.opponent) {
+            Outcome::Win => wins += 1,
+            Outcome::Draw => draws += 1,
+            Outcome::Loss => losses += 1,
+        }
+    }
+    (wins, draws, losses)
+}
+
+fn word(outcome: Outcome) -> &'static str {
+    match outcome {
+        Outcome::Loss => "loss",
+        Outcome::Draw => "draw",
+        Outcome::Win => "win",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub readings: Vec<Reading>,
+    /// The best shape to play each round, were the second column free.
+    pub optimal: Vec<Play>,
+    pub best: i32,
+}
+
+impl Analysis {
+    /// Follows `guide` under `rules` for every reading of `columns`. The
+    /// optimal play only depends on the first column of the guide.
+    pub fn new(rules: &Rules, guide: &str, columns: &[Column]) -> Result<Analysis, Error> {
+        let readings = columns
+            .iter()
+            .map(|&column| {
+                Ok(Reading::new(
+                    rules,
+                    column,
+                    rules.parse_guide(guide, column)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let optimal: Vec<Play> = rules
+            .parse_guide(guide, Column::Shape)?
+            .iter()
+            .map(|play| {
+                let mine = (0..rules.len())
+                    .max_by_key(|&mine| {
+                        (
+                            rules.score(Play {
+                                opponent: play.opponent,
+                                mine,
+                            }),
+                            mine,
+                        )
+                    })
+                    .expect("a game has shapes");
+                Play {
+                    opponent: play.opponent,
+                    mine,
+                }
+            })
+            .collect();
+        Ok(Analysis {
+            best: rules.total_score(&optimal),
+            readings,
+            optimal,
+        })
+    }
+
+    /// A summary of every reading against the optimal play, then the play of
+    /// every round if `rounds`.
+    pub fn report(&self, rules: &Rules, rounds: bool) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<8} {:>8} {:>6} {:>6} {:>6} {:>8}",
+            "reading", "score", "wins", "draws", "losses", "gap"
+        );
+        for r in &self.readings {
+            let gap = self.best - r.score;
+            let _ = writeln!(
+                out,
+                "{:<8} {:>8} {:>6} {:>6} {:>6} {:>8}",
+                r.name(),
+                r.score,
+                r.wins,
+                r.draws,
+                r.losses,
+                gap
+            );
+        }
+        let (wins, draws, losses) = tally(rules, &self.optimal);
+        let _ = writeln!(
+            out,
+            "{:<8} {:>8} {:>6} {:>6} {:>6} {:>8}",
+            "optimal", self.best, wins, draws, losses, 0
+        );
+        for r in &self.readings {
+            if self.best != 0 {
+                let _ = writeln!(
+                    out,
+                    "Reading the second column as {} scores {:.1}% of the best play.",
+                    r.name(),
+                    100.0 * r.score as f64 / self.best as f64
+                );
+            }
+        }
+        if rounds {
+            let _ = writeln!(out);
+            let _ = write!(out, "{:>6} {:<10}", "round", "opponent");
+            for r in &self.readings {
+                let _ = write!(out, " {:<22}", r.name());
+            }
+            let _ = writeln!(out, " optimal");
+            for (i, best) in self.optimal.iter().enumerate() {
+                let _ = write!(out, "{:>6} {:<10}", i + 1, rules.shapes[best.opponent].name);
+                for r in &self.readings {
+                    let play = r.plays[i];
+                    let play = format!(
+                        "{} {} {}",
+                        rules.shapes[play.mine].name,
+                        word(rules.outcome(play.mine, play.opponent)),
+                        r.scores[i]
+                    );
+                    let _ = write!(out, " {:<22}", play);
+                }
+                let _ = writeln!(
+                    out,
+                    " {} {} {}",
+                    rules.shapes[best.mine].name,
+                    word(rules.outcome(best.mine, best.opponent)),
+                    rules.score(*best)
+                );
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,209 @@
+use common::{Answer, Error, Solution};
+use log::trace;
+
+pub mod analysis;
+pub mod rules;
+
+// A for Rock, B for Paper, and C for Scissors.
+// X, Y and Z are read as a shape or as an outcome, see `Column`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// The shape of the opponent, `A`, `B` or `C`.
+    pub fn from_letter(letter: &str) -> Option<Shape> {
+        match letter {
+            "A" => Some(Shape::Rock),
+            "B" => Some(Shape::Paper),
+            "C" => Some(Shape::Scissors),
+            _ => None,
+        }
+    }
+
+    /// The shape this one defeats.
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape defeating this one.
+    pub fn loses_to(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        }
+    }
+
+    /// Points for playing this shape, whatever the outcome.
+    pub fn score(self) -> i32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    /// Outcome of playing this shape against `opponent`.
+    pub fn against(self, opponent: Shape) -> Outcome {
+        if self.beats() == opponent {
+            Outcome::Win
+        } else if self.loses_to() == opponent {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> i32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+
+    /// The shape to play against `opponent` to get this outcome.
+    pub fn shape_against(self, opponent: Shape) -> Shape {
+        match self {
+            Outcome::Loss => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.loses_to(),
+        }
+    }
+}
+
+/// Second column of the strategy guide, `X`, `Y` or `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Code {
+    X,
+    Y,
+    Z,
+}
+
+impl Code {
+    pub fn from_letter(letter: &str) -> Option<Code> {
+        match letter {
+            "X" => Some(Code::X),
+            "Y" => Some(Code::Y),
+            "Z" => Some(Code::Z),
+            _ => None,
+        }
+    }
+
+    /// X is rock, Y paper and Z scissors.
+    pub fn as_shape(self) -> Shape {
+        match self {
+            Code::X => Shape::Rock,
+            Code::Y => Shape::Paper,
+            Code::Z => Shape::Scissors,
+        }
+    }
+
+    /// X means you need to lose, Y a draw and Z a win.
+    pub fn as_outcome(self) -> Outcome {
+        match self {
+            Code::X => Outcome::Loss,
+            Code::Y => Outcome::Draw,
+            Code::Z => Outcome::Win,
+        }
+    }
+}
+
+/// How the second column of the strategy guide is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// The shape to play, as guessed in part one.
+    Shape,
+    /// The outcome the round must end with, as in part two.
+    Outcome,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub code: Code,
+}
+
+impl Round {
+    /// The shape played when the second column is read as `column`.
+    pub fn play(&self, column: Column) -> Shape {
+        match column {
+            Column::Shape => self.code.as_shape(),
+            Column::Outcome => self.code.as_outcome().shape_against(self.opponent),
+        }
+    }
+
+    pub fn score(&self, column: Column) -> i32 {
+        let shape = self.play(column);
+        shape.score() + shape.against(self.opponent).score()
+    }
+}
+
+/// Total score of following the guide, reading the second column as `column`.
+pub fn total_score(rounds: &[Round], column: Column) -> i32 {
+    let mut pts = 0;
+    for round in rounds {
+        let tmp_pts = round.score(column);
+        pts += tmp_pts;
+        trace!("{:?} {} {}", round, tmp_pts, pts);
+    }
+    pts
+}
+
+fn parse(input: &str) -> Result<Vec<Round>, Error> {
+    common::lines(input)
+        .map(|l| {
+            let (x, y) = l.split_once(l.text, " ")?;
+            let opponent = Shape::from_letter(x).ok_or_else(|| l.error(x, "A, B or C"))?;
+            let code = Code::from_letter(y).ok_or_else(|| l.error(y, "X, Y or Z"))?;
+            Ok(Round { opponent, code })
+        })
+        .collect()
+}
+
+fn part_one(rounds: &[Round]) -> i32 {
+    total_score(rounds, Column::Shape)
+}
+
+fn part_two(rounds: &[Round]) -> i32 {
+    total_score(rounds, Column::Outcome)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    // Opponent shape and second column of each round.
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(rounds: &Self::Input) -> Answer {
+        part_one(rounds).into()
+    }
+
+    fn part2(rounds: &Self::Input) -> Answer {
+        part_two(rounds).into()
+    }
+}
@@ -0,0 +1,240 @@
+//! Cyclic games generalising rock paper scissors to any odd number of shapes.
+//!
+//! Rules are read from a config file, see `rules/rps.txt`, with one line per
+//! shape in cycle order and one line per outcome. Each shape beats the shapes
+//! an odd number of places before it in the cycle, so with an odd number of
+//! shapes every shape beats exactly half of the others.
+
+use common::{Error, Line};
+
+use crate::{Column, Outcome};
+
+/// The rules of the puzzle.
+pub const CLASSIC: &str = include_str!("../rules/rps.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeRule {
+    pub name: String,
+    /// Letter of the shape in the first column of the guide.
+    pub opponent: String,
+    /// Letter of the shape in the second column, read as a shape.
+    pub player: String,
+    pub score: i32,
+}
+
+/// A round of the guide once the second column is read: the opponent and the
+/// player shapes, as indexes in `Rules::shapes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub opponent: usize,
+    pub mine: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub shapes: Vec<ShapeRule>,
+    /// Letter and score of a loss, a draw and a win.
+    outcomes: [(String, i32); 3],
+}
+
+const OUTCOMES: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+fn slot(outcome: Outcome) -> usize {
+    match outcome {
+        Outcome::Loss => 0,
+        Outcome::Draw => 1,
+        Outcome::Win => 2,
+    }
+}
+
+/// Whitespace separated words of a line, reported where they are missing.
+struct Words<'a, 'l> {
+    line: &'l Line<'a>,
+    words: std::str::SplitWhitespace<'a>,
+}
+
+impl<'a> Words<'a, '_> {
+    fn next(&mut self, expected: &str) -> Result<&'a str, Error> {
+        let text = self.line.text;
+        self.words
+            .next()
+            .ok_or_else(|| self.line.error(&text[text.len()..], expected))
+    }
+
+    fn end(&mut self) -> Result<(), Error> {
+        match self.words.next() {
+            Some(word) => Err(self.line.error(word, "end of line")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Rules {
+    /// Rock paper scissors with the scores of the puzzle.
+    pub fn classic() -> Rules {
+        Rules::parse(CLASSIC).expect("the bundled rules are valid")
+    }
+
+    /// Reads `shape <name> <opponent letter> <player letter> <score>` and
+    /// `outcome <loss|draw|win> <letter> <score>` lines. Blank lines and lines
+    /// starting with `#` are skipped.
+    pub fn parse(config: &str) -> Result<Rules, Error> {
+        let mut shapes: Vec<ShapeRule> = vec![];
+        let mut outcomes: [Option<(String, i32)>; 3] = [None, None, None];
+        let mut last = 0;
+        for l in common::lines(config) {
+            last = l.number;
+            let text = l.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let mut words = Words {
+                line: &l,
+                words: l.text.split_whitespace(),
+            };
+            let kind = words.next("`shape` or `outcome`")?;
+            match kind {
+                "shape" => {
+                    let name = words.next("a shape name")?;
+                    let opponent = words.next("the opponent letter")?;
+                    let player = words.next("the player letter")?;
+                    let score = words.next("a score")?;
+                    let score = l.parse(score, "a score")?;
+                    words.end()?;
+                    if shapes.iter().any(|s| s.name == name) {
+                        return Err(l.error(name, "a new shape name"));
+                    }
+                    if shapes.iter().any(|s| s.opponent == opponent) {
+                        return Err(l.error(opponent, "an unused opponent letter"));
+                    }
+                    if shapes.iter().any(|s| s.player == player) {
+                        return Err(l.error(player, "an unused player letter"));
+                    }
+                    shapes.push(ShapeRule {
+                        name: name.to_string(),
+                        opponent: opponent.to_string(),
+                        player: player.to_string(),
+                        score,
+                    });
+                }
+                "outcome" => {
+                    let outcome = words.next("loss, draw or win")?;
+                    let outcome = match outcome {
+                        "loss" => Outcome::Loss,
+                        "draw" => Outcome::Draw,
+                        "win" => Outcome::Win,
+                        _ => return Err(l.error(outcome, "loss, draw or win")),
+                    };
+                    let letter = words.next("the outcome letter")?;
+                    let score = words.next("a score")?;
+                    let score = l.parse(score, "a score")?;
+                    words.end()?;
+                    if outcomes.iter().flatten().any(|(used, _)| used == letter) {
+                        return Err(l.error(letter, "an unused outcome letter"));
+                    }
+                    if outcomes[slot(outcome)].is_some() {
+                        return Err(l.error(l.text, "a single line per outcome"));
+                    }
+                    outcomes[slot(outcome)] = Some((letter.to_string(), score));
+                }
+                _ => return Err(l.error(kind, "`shape` or `outcome`")),
+            }
+        }
+        if shapes.len() < 3 || shapes.len().is_multiple_of(2) {
+            return Err(Error::end_of_input(
+                last + 1,
+                "another shape, a cyclic game needs an odd number of at least 3 shapes",
+            ));
+        }
+        let [Some(loss), Some(draw), Some(win)] = outcomes else {
+            return Err(Error::end_of_input(
+                last + 1,
+                "an outcome line for loss, draw and win",
+            ));
+        };
+        Ok(Rules {
+            shapes,
+            outcomes: [loss, draw, win],
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Whether shape `a` defeats shape `b`.
+    pub fn beats(&self, a: usize, b: usize) -> bool {
+        (a + self.len() - b) % self.len() % 2 == 1
+    }
+
+    /// Outcome of playing `mine` against `opponent`.
+    pub fn outcome(&self, mine: usize, opponent: usize) -> Outcome {
+        if mine == opponent {
+            Outcome::Draw
+        } else if self.beats(mine, opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        self.outcomes[slot(outcome)].1
+    }
+
+    /// The best scoring shape to play against `opponent` to get `outcome`.
+    pub fn shape_for(&self, outcome: Outcome, opponent: usize) -> usize {
+        (0..self.len())
+            .filter(|&mine| self.outcome(mine, opponent) == outcome)
+            .max_by_key(|&mine| self.shapes[mine].score)
+            .expect("every outcome is reachable in a cyclic game")
+    }
+
+    pub fn score(&self, play: Play) -> i32 {
+        self.shapes[play.mine].score + self.outcome_score(self.outcome(play.mine, play.opponent))
+    }
+
+    pub fn total_score(&self, plays: &[Play]) -> i32 {
+        plays.iter().map(|&play| self.score(play)).sum()
+    }
+
+    /// Reads a strategy guide under these rules, the second column being read
+    /// as `column`.
+    pub fn parse_guide(&self, input: &str, column: Column) -> Result<Vec<Play>, Error> {
+        let letters = |letters: Vec<&str>| letters.join(", ");
+        common::lines(input)
+            .map(|l| {
+                let (x, y) = l.split_once(l.text, " ")?;
+                let opponent = self
+                    .shapes
+                    .iter()
+                    .position(|s| s.opponent == x)
+                    .ok_or_else(|| {
+                        l.error(
+                            x,
+                            letters(self.shapes.iter().map(|s| s.opponent.as_str()).collect()),
+                        )
+                    })?;
+                let mine = match column {
+                    Column::Shape => self.shapes.iter().position(|s| s.player == y),
+                    Column::Outcome => OUTCOMES
+                        .into_iter()
+                        .find(|&o| self.outcomes[slot(o)].0 == y)
+                        .map(|o| self.shape_for(o, opponent)),
+                };
+                let mine = mine.ok_or_else(|| {
+                    let expected = match column {
+                        Column::Shape => self.shapes.iter().map(|s| s.player.as_str()).collect(),
+                        Column::Outcome => self.outcomes.iter().map(|(l, _)| l.as_str()).collect(),
+                    };
+                    l.error(y, letters(expected))
+                })?;
+                Ok(Play { opponent, mine })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,300 @@
+/*
+--- Day 3: Rucksack Reorganization ---
+
+One Elf has the important job of loading all of the rucksacks with supplies for the jungle journey.
+Unfortunately, that Elf didn't quite follow the packing instructions, and so a few items now need to be rearranged.
+
+Each rucksack has two large compartments. All items of a given type are meant to go into exactly one of the two compartments.
+The Elf that did the packing failed to follow this rule for exactly one item type per rucksack.
+
+The Elves have made a list of all of the items currently in each rucksack (your puzzle input), but they need your help finding the errors.
+Every item type is identified by a single lowercase or uppercase letter (that is, a and A refer to different types of items).
+
+The list of items for each rucksack is given as characters all on a single line.
+A given rucksack always has the same number of items in each of its two compartments,
+so the first half of the characters represent items in the first compartment,
+while the second half of the characters represent items in the second compartment.
+
+For example, suppose you have the following list of contents from six rucksacks:
+
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+
+    The first rucksack contains the items vJrwpWtwJgWrhcsFMMfFFhFp,
+    which means its first compartment contains the items vJrwpWtwJgWr,
+    while the second compartment contains the items hcsFMMfFFhFp.
+    The only item type that appears in both compartments is lowercase p.
+
+    The second rucksack's compartments contain jqHRNqRjqzjGDLGL and rsFMfFZSrLrFZsSL. The only item type that appears in both compartments is uppercase L.
+    The third rucksack's compartments contain PmmdzqPrV and vPwwTWBwg; the only common item type is uppercase P.
+    The fourth rucksack's compartments only share item type v.
+    The fifth rucksack's compartments only share item type t.
+    The sixth rucksack's compartments only share item type s.
+
+To help prioritize item rearrangement, every item type can be converted to a priority:
+
+    Lowercase item types a through z have priorities 1 through 26.
+    Uppercase item types A through Z have priorities 27 through 52.
+
+In the above example, the priority of the item type that appears in both compartments of each rucksack is 16 (p), 38 (L), 42 (P), 22 (v), 20 (t), and 19 (s); the sum of these is 157.
+
+Find the item type that appears in both compartments of each rucksack. What is the sum of the priorities of those item types?
+
+Your puzzle answer was 8401.
+*/
+use common::{Answer, Error, Line, Solution};
+use log::debug;
+
+pub mod validate;
+
+// Priority of every byte, 0 for anything but letters.
+const PRIORITIES: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 26 {
+        table[b'a' as usize + i] = i as u8 + 1;
+        table[b'A' as usize + i] = i as u8 + 27;
+        i += 1;
+    }
+    table
+};
+
+/// Priority of an item type: a through z are 1 through 26, A through Z are 27
+/// through 52.
+pub fn priority(item: u8) -> Option<u32> {
+    match PRIORITIES[item as usize] {
+        0 => None,
+        p => Some(p as u32),
+    }
+}
+
+fn item(priority: u32) -> char {
+    if priority <= 26 {
+        (b'a' + (priority - 1) as u8) as char
+    }
+    else {
+        (b'A' + (priority - 27) as u8) as char
+    }
+}
+
+// Bit `priority` set for each item type, bit 0 for anything but letters.
+fn mask(items: &[u8]) -> u64 {
+    let mut mask = 0;
+    for &b in items {
+        mask |= 1u64 << PRIORITIES[b as usize];
+    }
+    mask
+}
+
+/// Set of item types, as a mask with bit `priority - 1` set for each type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Items(u64);
+
+impl Items {
+    pub const ALL: Items = Items((1 << 52) - 1);
+
+    /// Item types of `items`, anything but letters is left out.
+    pub fn from_bytes(items: &[u8]) -> Items {
+        Items(mask(items) >> 1)
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Item types in priority order.
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        let mut mask = self.0;
+        std::iter::from_fn(move || {
+            if mask == 0 {
+                return None;
+            }
+            let bit = mask.trailing_zeros();
+            mask &= mask - 1;
+            Some(item(bit + 1))
+        })
+    }
+
+    /// Sum of the priorities of the item types, the priority of the type when
+    /// there is a single one as the puzzle promises.
+    pub fn priority(self) -> u32 {
+        let mut mask = self.0;
+        let mut sum = 0;
+        while mask != 0 {
+            sum += mask.trailing_zeros() + 1;
+            mask &= mask - 1;
+        }
+        sum
+    }
+}
+
+/// Items of the two compartments of a rucksack, the first and second half of
+/// its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub first: Items,
+    pub second: Items,
+}
+
+impl Rucksack {
+    /// Reads a line of item letters, as many in each compartment.
+    pub fn parse(l: &Line) -> Result<Rucksack, Error> {
+        let bytes = l.text.as_bytes();
+        let mid = bytes.len() / 2;
+        let first = mask(&bytes[..mid]);
+        let second = mask(&bytes[bytes.len() - mid..]);
+        if (first | second | mask(&bytes[mid..bytes.len() - mid])) & 1 == 1 {
+            let i = bytes.iter().position(|&b| PRIORITIES[b as usize] == 0).unwrap_or(0);
+            return Err(l.error(&l.text[i..], "an item letter"));
+        }
+        if !bytes.len().is_multiple_of(2) {
+            return Err(l.error(l.text, "an even number of items"));
+        }
+        Ok(Rucksack { first: Items(first >> 1), second: Items(second >> 1) })
+    }
+
+    /// Item types found in both compartments.
+    pub fn shared(&self) -> Items {
+        self.first.intersection(self.second)
+    }
+
+    pub fn items(&self) -> Items {
+        Items(self.first.0 | self.second.0)
+    }
+}
+
+/// Item types carried by every rucksack of each group of `size` rucksacks. A
+/// last group with less rucksacks is left out.
+pub fn badges(rucksacks: &[Rucksack], size: usize) -> impl Iterator<Item = Items> + '_ {
+    rucksacks.chunks_exact(size).map(|group| {
+        group.iter().fold(Items::ALL, |badge, r| badge.intersection(r.items()))
+    })
+}
+
+/// Both sums of priorities, computed one line at a time so inputs do not
+/// have to fit in memory.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sums {
+    pub rucksacks: usize,
+    /// Priorities of the items shared by the compartments, as in part one.
+    pub shared: u64,
+    pub groups: usize,
+    /// Priorities of the badges of the groups, as in part two.
+    pub badges: u64,
+}
+
+pub struct Scanner {
+    group: usize,
+    badge: Items,
+    in_group: usize,
+    sums: Sums,
+}
+
+impl Scanner {
+    pub fn new(group: usize) -> Scanner {
+        Scanner { group, badge: Items::ALL, in_group: 0, sums: Sums::default() }
+    }
+
+    /// Adds the rucksack of line `number`.
+    pub fn push(&mut self, number: usize, text: &str) -> Result<(), Error> {
+        let rucksack = Rucksack::parse(&Line { number, text })?;
+        self.sums.rucksacks += 1;
+        self.sums.shared += rucksack.shared().priority() as u64;
+        self.badge = self.badge.intersection(rucksack.items());
+        self.in_group += 1;
+        if self.in_group == self.group {
+            self.sums.groups += 1;
+            self.sums.badges += self.badge.priority() as u64;
+            self.badge = Items::ALL;
+            self.in_group = 0;
+        }
+        Ok(())
+    }
+
+    pub fn sums(&self) -> &Sums {
+        &self.sums
+    }
+}
+
+fn part_one(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks.iter().map(|r| r.shared().priority()).sum()
+}
+
+/*
+--- Part Two ---
+
+As you finish identifying the misplaced items, the Elves come to you with another issue.
+
+For safety, the Elves are divided into groups of three. Every Elf carries a badge that identifies their group.
+For efficiency, within each group of three Elves, the badge is the only item type carried by all three Elves.
+That is, if a group's badge is item type B, then all three Elves will have item type B somewhere in their rucksack, and at most two of the Elves will be carrying any other item type.
+
+The problem is that someone forgot to put this year's updated authenticity sticker on the badges.
+All of the badges need to be pulled out of the rucksacks so the new authenticity stickers can be attached.
+
+Additionally, nobody wrote down which item type corresponds to each group's badges.
+The only way to tell which item type is the right one is by finding the one item type that is common between all three Elves in each group.
+
+Every set of three lines in your list corresponds to a single group, but each group can have a different badge item type.
+So, in the above example, the first group's rucksacks are the first three lines:
+
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+
+And the second group's rucksacks are the next three lines:
+
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+
+In the first group, the only item type that appears in all three rucksacks is lowercase r; this must be their badges. In the second group, their badge item type must be Z.
+
+Priorities for these items must still be found to organize the sticker attachment efforts:
+here, they are 18 (r) for the first group and 52 (Z) for the second group. The sum of these is 70.
+
+Find the item type that corresponds to the badges of each three-Elf group. What is the sum of the priorities of those item types?
+
+Your puzzle answer was 2641.
+*/
+fn part_two(rucksacks: &[Rucksack]) -> u32 {
+    let mut sum:u32 = 0;
+    for badge in badges(rucksacks, 3) {
+        sum += badge.priority();
+        debug!("{:?}", badge.iter().collect::<String>());
+    }
+    sum
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    // Item types of both compartments of each rucksack.
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        common::lines(input)
+            .map(|l| Rucksack::parse(&l))
+            .collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        part_one(rucksacks).into()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        part_two(rucksacks).into()
+    }
+}
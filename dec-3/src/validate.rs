@@ -0,0 +1,184 @@
+//! Checks a list of rucksacks against what the puzzle promises, reporting
+//! every line that breaks it instead of stopping at the first one.
+
+use std::fmt;
+
+use crate::{mask, Items, PRIORITIES};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anomaly {
+    /// The middle item of the line belongs to no compartment.
+    OddLength {
+        line: usize,
+        items: usize,
+    },
+    NotALetter {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    NoSharedItem {
+        line: usize,
+    },
+    SharedItems {
+        line: usize,
+        items: String,
+    },
+    /// A group of rucksacks, from line `first` to line `last`, without badge.
+    NoBadge {
+        first: usize,
+        last: usize,
+    },
+    Badges {
+        first: usize,
+        last: usize,
+        items: String,
+    },
+    /// The last group, from line `first` to line `last`, lacks rucksacks.
+    IncompleteGroup {
+        first: usize,
+        last: usize,
+        size: usize,
+    },
+}
+
+/// `line[:column]: message`, to be prefixed with the file name. Group
+/// anomalies are reported on the first line of the group.
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::OddLength { line, items } => write!(
+                f,
+                "{}: odd number of items ({}), the middle one is in no compartment",
+                line, items
+            ),
+            Anomaly::NotALetter {
+                line,
+                column,
+                found,
+            } => {
+                write!(f, "{}:{}: `{}` is not an item letter", line, column, found)
+            }
+            Anomaly::NoSharedItem { line } => {
+                write!(f, "{}: no item type in both compartments", line)
+            }
+            Anomaly::SharedItems { line, items } => {
+                write!(
+                    f,
+                    "{}: several item types in both compartments: {}",
+                    line, items
+                )
+            }
+            Anomaly::NoBadge { first, last } => {
+                write!(
+                    f,
+                    "{}: no badge in the group of lines {}-{}",
+                    first, first, last
+                )
+            }
+            Anomaly::Badges { first, last, items } => write!(
+                f,
+                "{}: several badges in the group of lines {}-{}: {}",
+                first, first, last, items
+            ),
+            Anomaly::IncompleteGroup { first, last, size } => write!(
+                f,
+                "{}: the last group, lines {}-{}, has {} rucksacks instead of {}",
+                first,
+                first,
+                last,
+                last - first + 1,
+                size
+            ),
+        }
+    }
+}
+
+/// Validates rucksacks one line at a time, like `Scanner` sums them.
+pub struct Validator {
+    group: usize,
+    badge: Items,
+    in_group: usize,
+    first: usize,
+    last: usize,
+    anomalies: Vec<Anomaly>,
+}
+
+impl Validator {
+    pub fn new(group: usize) -> Validator {
+        Validator {
+            group,
+            badge: Items::ALL,
+            in_group: 0,
+            first: 0,
+            last: 0,
+            anomalies: vec![],
+        }
+    }
+
+    /// Checks the rucksack of line `number`, items which are not letters are
+    /// reported then left out.
+    pub fn push(&mut self, number: usize, text: &str) {
+        let bytes = text.as_bytes();
+        if !bytes.len().is_multiple_of(2) {
+            self.anomalies.push(Anomaly::OddLength {
+                line: number,
+                items: text.chars().count(),
+            });
+        }
+        for (i, c) in text.char_indices() {
+            if !c.is_ascii() || PRIORITIES[c as usize] == 0 {
+                self.anomalies.push(Anomaly::NotALetter {
+                    line: number,
+                    column: i + 1,
+                    found: c,
+                });
+            }
+        }
+        let mid = bytes.len() / 2;
+        let first = Items::from_bytes(&bytes[..mid]);
+        let second = Items::from_bytes(&bytes[bytes.len() - mid..]);
+        let shared = first.intersection(second);
+        match shared.len() {
+            0 => self.anomalies.push(Anomaly::NoSharedItem { line: number }),
+            1 => {}
+            _ => self.anomalies.push(Anomaly::SharedItems {
+                line: number,
+                items: shared.iter().collect(),
+            }),
+        }
+
+        if self.in_group == 0 {
+            self.first = number;
+        }
+        self.in_group += 1;
+        self.last = number;
+        self.badge = self.badge.intersection(Items(mask(bytes) >> 1));
+        if self.in_group == self.group {
+            let (first, last) = (self.first, number);
+            match self.badge.len() {
+                0 => self.anomalies.push(Anomaly::NoBadge { first, last }),
+                1 => {}
+                _ => self.anomalies.push(Anomaly::Badges {
+                    first,
+                    last,
+                    items: self.badge.iter().collect(),
+                }),
+            }
+            self.badge = Items::ALL;
+            self.in_group = 0;
+        }
+    }
+
+    /// Every anomaly found, in the order of the lines where they are found.
+    pub fn finish(mut self) -> Vec<Anomaly> {
+        if self.in_group > 0 {
+            self.anomalies.push(Anomaly::IncompleteGroup {
+                first: self.first,
+                last: self.last,
+                size: self.group,
+            });
+        }
+        self.anomalies
+    }
+}
@@ -0,0 +1,64 @@
+//! How the whole camp is covered once every assignment is put together.
+
+use crate::set::{deeper_than, IntervalSet};
+use crate::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// Sections of the camp, by default from the first to the last assigned.
+    pub camp: Option<Range>,
+    /// Sections of the camp assigned to at least one elf.
+    pub covered: IntervalSet,
+    /// Sections of the camp nobody is assigned.
+    pub uncovered: IntervalSet,
+    pub k: usize,
+    /// Sections of the camp assigned to more than `k` elves.
+    pub crowded: IntervalSet,
+    /// Pairs, by index, whose sections are all cleaned by other pairs anyway.
+    pub redundant: Vec<usize>,
+}
+
+impl Coverage {
+    /// Sections outside of `camp`, when given, are left out of `covered` and
+    /// `crowded`.
+    pub fn new(pairs: &[(Range, Range)], k: usize, camp: Option<Range>) -> Coverage {
+        let assigned: Vec<Range> = pairs
+            .iter()
+            .flat_map(|&(a, b)| [a, b])
+            .filter_map(|r| match camp {
+                Some(camp) => r.intersection(&camp),
+                None => Some(r),
+            })
+            .collect();
+        let covered: IntervalSet = assigned.iter().copied().collect();
+        let camp = camp.or_else(|| covered.hull());
+        let uncovered = match camp {
+            Some(camp) => covered.complement(camp),
+            None => IntervalSet::new(),
+        };
+        let crowded = deeper_than(assigned, k);
+
+        // A pair counts once for each of its sections, even when both elves
+        // clean it, so a section cleaned by more than one pair is in `shared`.
+        let by_pair: Vec<IntervalSet> = pairs
+            .iter()
+            .map(|&(a, b)| [a, b].into_iter().collect())
+            .collect();
+        let shared = deeper_than(by_pair.iter().flat_map(|s| s.ranges().to_vec()), 1);
+        let redundant = by_pair
+            .iter()
+            .enumerate()
+            .filter(|(_, sections)| sections.ranges().iter().all(|r| shared.contains(r)))
+            .map(|(i, _)| i)
+            .collect();
+
+        Coverage {
+            camp,
+            covered,
+            uncovered,
+            k,
+            crowded,
+            redundant,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Assignments drawn as in the puzzle text, one column per section:
+//!
+//! ```text
+//! .234.....  2-4
+//! .....678.  6-8
+//! ```
+//!
+//! A section is drawn with the last digit of its ID. Above section 9, a ruler
+//! on top gives the other digits of every column.
+
+use std::fmt::Write;
+
+use crate::Range;
+
+fn digit(n: u32) -> char {
+    char::from_digit(n % 10, 10).expect("a decimal digit")
+}
+
+fn cells(span: Range, glyph: impl FnMut(u32) -> char) -> String {
+    span.sections().map(glyph).collect()
+}
+
+/// Digits of the IDs of the columns above the last one, from the highest,
+/// then the last digit of each column. Empty when every ID is a single digit.
+pub fn ruler(span: Range) -> String {
+    let mut out = String::new();
+    if span.end() < 10 {
+        return out;
+    }
+    let mut places = vec![];
+    let mut place = 10u64;
+    while place <= span.end() as u64 {
+        places.push(place);
+        place *= 10;
+    }
+    for &place in places.iter().rev() {
+        let line = cells(span, |s| {
+            if s as u64 >= place {
+                digit((s as u64 / place) as u32)
+            } else {
+                ' '
+            }
+        });
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    let _ = writeln!(out, "{}", cells(span, digit));
+    out
+}
+
+/// The sections of `range` within `span`, followed by the range itself.
+pub fn row(span: Range, range: Range) -> String {
+    let line = cells(span, |s| {
+        if range.contains_section(s) {
+            digit(s)
+        } else {
+            '.'
+        }
+    });
+    format!("{}  {}", line, range)
+}
+
+/// Every pair on two rows, a third one marking with `X` the sections both
+/// elves clean when they overlap. A range fully containing the other is told
+/// so on its row.
+pub fn pairs(pairs: &[(Range, Range)], span: Range) -> String {
+    let mut out = ruler(span);
+    for (i, (a, b)) in pairs.iter().enumerate() {
+        if i > 0 || !out.is_empty() {
+            out.push('\n');
+        }
+        let (mut first, mut second) = (row(span, *a), row(span, *b));
+        if a == b {
+            second.push_str(" same sections");
+        } else if a.contains(b) {
+            let _ = write!(first, " contains {}", b);
+        } else if b.contains(a) {
+            let _ = write!(second, " contains {}", a);
+        }
+        let _ = writeln!(out, "{}", first);
+        let _ = writeln!(out, "{}", second);
+        if let Some(shared) = a.intersection(b) {
+            let marks = cells(span, |s| if shared.contains_section(s) { 'X' } else { '.' });
+            let _ = writeln!(out, "{}  overlap {}", marks, shared);
+        }
+    }
+    out
+}
+
+/// Every assignment of the camp on its own row, then the number of elves
+/// cleaning each section: `.` for none, `+` for more than nine.
+pub fn camp(pairs: &[(Range, Range)], span: Range) -> String {
+    let mut out = ruler(span);
+    let ranges: Vec<Range> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+    for range in &ranges {
+        let _ = writeln!(out, "{}", row(span, *range));
+    }
+    // +1 where an elf starts within the span, -1 after it is done.
+    let mut events: Vec<(u64, i64)> = ranges
+        .iter()
+        .filter_map(|r| r.intersection(&span))
+        .flat_map(|r| [(r.start() as u64, 1), (r.end() as u64 + 1, -1)])
+        .collect();
+    events.sort_unstable();
+    let mut events = events.into_iter().peekable();
+    let mut elves = 0;
+    let depth = cells(span, |s| {
+        while let Some((_, change)) = events.next_if(|&(at, _)| at <= s as u64) {
+            elves += change;
+        }
+        match elves {
+            0 => '.',
+            n @ 1..=9 => digit(n as u32),
+            _ => '+',
+        }
+    });
+    let _ = writeln!(out, "{}  elves per section", depth);
+    out
+}
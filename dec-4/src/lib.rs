@@ -56,53 +56,35 @@ In this example, there are 2 such pairs.
 In how many assignment pairs does one range fully contain the other?
  */
 
-use std::fs::File;
-use std::io::BufReader;
-use std::io::prelude::*;
-
-#[derive(Debug)]
-struct SE {
-    s: i32,
-    e: i32
+use common::{Answer, Error, Line, Solution};
+use log::debug;
+
+pub mod coverage;
+pub mod draw;
+pub mod range;
+pub mod set;
+
+pub use range::Range;
+
+/// Reads the range of `part` of a line, reported where it goes wrong.
+pub fn parse_range(line: &Line, part: &str) -> Result<Range, Error> {
+    part.parse()
+        .map_err(|e: range::RangeError| line.error(&part[e.offset..], e.expected))
+}
+
+fn parse(input: &str) -> Result<Vec<(Range, Range)>, Error> {
+    common::lines(input)
+        .map( |line| {
+            let (e1, e2) = line.split_once(line.text, ",")?;
+            Ok((parse_range(&line, e1)?, parse_range(&line, e2)?))
+        }).collect()
 }
 
-fn part_one() -> std::io::Result<()> {
-    
-    let file = File::open("input-test.txt")?;
-    let reader = BufReader::new(file);
-    let mut cnt = 0;
-    let x: _ = reader
-        .lines()
-        .map( |l| {
-            let line = l.unwrap();
-            let mut elves_pair = line.split(",");
-            let e1 = elves_pair.next().unwrap();
-            let e2 = elves_pair.next().unwrap();
-            let mut e1_s = e1.split("-");
-            let mut e2_s = e2.split("-");
-            let int1 = SE{
-                s:e1_s.next().unwrap().parse::<i32>().unwrap(),
-                e:e1_s.next().unwrap().parse::<i32>().unwrap(),
-            };
-            let int2 = SE{
-                s:e2_s.next().unwrap().parse::<i32>().unwrap(),
-                e:e2_s.next().unwrap().parse::<i32>().unwrap(),
-            };
-    
-            (int1, int2)
-    
-        }).collect::<Vec<_>>();
-    
-    for (i1, i2) in x {
-        // i2 in i1 || i1 in i2
-        if i1.s <= i2.s && i2.e <= i1.e || i2.s <= i1.s && i1.e <= i2.e {
-            println!("{:?} {:?} - {:?} {:?}", i1.s, i1.e, i2.s, i2.e);
-            cnt += 1;
-        }
-    } 
-    println!("cnt {}", cnt);
-        
-    Ok(())
+fn part_one(pairs: &[(Range, Range)]) -> usize {
+    pairs.iter()
+        .filter(|(i1, i2)| i1.contains(i2) || i2.contains(i1))
+        .inspect(|(i1, i2)| debug!("{} - {}", i1, i2))
+        .count()
 }
 /*
 --- Part Two ---
@@ -120,43 +102,28 @@ don't overlap, while the remaining four pairs (5-7,7-9, 2-8,3-7, 6-6,4-6, and 2-
 
 So, in this example, the number of overlapping assignment pairs is 4.
 */
-fn part_two() -> std::io::Result<()> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
-    let mut cnt = 0;
-    let x: _ = reader
-        .lines()
-        .map( |l| {
-            let line = l.unwrap();
-            let mut elves_pair = line.split(",");
-            let e1 = elves_pair.next().unwrap();
-            let e2 = elves_pair.next().unwrap();
-            let mut e1_s = e1.split("-");
-            let mut e2_s = e2.split("-");
-            let int1 = SE{
-                s:e1_s.next().unwrap().parse::<i32>().unwrap(),
-                e:e1_s.next().unwrap().parse::<i32>().unwrap(),
-            };
-            let int2 = SE{
-                s:e2_s.next().unwrap().parse::<i32>().unwrap(),
-                e:e2_s.next().unwrap().parse::<i32>().unwrap(),
-            };
-    
-            (int1, int2)
-    
-        }).collect::<Vec<_>>();
-    
-    for (i1, i2) in x {
-        // i2 in i1 || i1 in i2
-        if i1.e >= i2.s && i2.e >= i1.s {
-            println!("{:?} {:?} - {:?} {:?}", i1.s, i1.e, i2.s, i2.e);
-            cnt += 1;
-        }
-    } 
-    println!("cnt {}", cnt);
-    Ok(())
+fn part_two(pairs: &[(Range, Range)]) -> usize {
+    pairs.iter()
+        .filter(|(i1, i2)| i1.overlaps(i2))
+        .inspect(|(i1, i2)| debug!("{} - {}", i1, i2))
+        .count()
 }
-fn main() {
-    part_one();
-    part_two();
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    // Section assignments of each pair of elves.
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        part_one(pairs).into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        part_two(pairs).into()
+    }
 }
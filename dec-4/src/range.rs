@@ -0,0 +1,129 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Sections `start` through `end`, both included. A range always holds at
+/// least one section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    /// `None` when `end` comes before `start`.
+    pub fn new(start: u32, end: u32) -> Option<Range> {
+        (start <= end).then_some(Range { start, end })
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// Number of sections, up to 2^32 for `0-4294967295`.
+    pub fn len(&self) -> u64 {
+        (self.end - self.start) as u64 + 1
+    }
+
+    /// Never, kept for symmetry with `len`.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn contains_section(&self, section: u32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    /// Whether every section of `other` is in this range.
+    pub fn contains(&self, other: &Range) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the ranges share at least one section.
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Range) -> Option<Range> {
+        Range::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The sections of both ranges, when they overlap or touch so that they
+    /// make a single range.
+    pub fn union(&self, other: &Range) -> Option<Range> {
+        let touch =
+            self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1);
+        touch.then(|| Range {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// The sections of this range which are not in `other`: none, one range
+    /// or two when `other` is strictly inside.
+    pub fn difference(&self, other: &Range) -> Vec<Range> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        let mut left = vec![];
+        if self.start < other.start {
+            left.push(Range {
+                start: self.start,
+                end: other.start - 1,
+            });
+        }
+        if other.end < self.end {
+            left.push(Range {
+                start: other.end + 1,
+                end: self.end,
+            });
+        }
+        left
+    }
+
+    /// Sections of the range, from the first.
+    pub fn sections(&self) -> std::ops::RangeInclusive<u32> {
+        self.start..=self.end
+    }
+}
+
+/// Written as the puzzle does, `2-4`.
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Why a range could not be read: what was `expected` at byte `offset` of the
+/// text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeError {
+    pub offset: usize,
+    pub expected: &'static str,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} at offset {}", self.expected, self.offset)
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// Reads `<start>-<end>`, `end` not before `start`.
+impl FromStr for Range {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Range, RangeError> {
+        let error = |offset, expected| RangeError { offset, expected };
+        let (start, end) = s.split_once('-').ok_or(error(s.len(), "`-`"))?;
+        let start = start.parse().map_err(|_| error(0, "a section"))?;
+        let end = end
+            .parse()
+            .map_err(|_| error(s.len() - end.len(), "a section"))?;
+        Range::new(start, end).ok_or(error(0, "an ascending range"))
+    }
+}
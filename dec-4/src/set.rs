@@ -0,0 +1,147 @@
+use std::fmt;
+
+use crate::Range;
+
+/// Sections as disjoint ranges, sorted and with touching ranges merged, so two
+/// sets holding the same sections are equal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// Adds the sections of `range`, merging it with the ranges it overlaps or
+    /// touches.
+    pub fn insert(&mut self, range: Range) {
+        // Ranges before `range` that do not touch it stay, as do those after.
+        let first = self
+            .ranges
+            .partition_point(|r| r.end().saturating_add(1) < range.start());
+        let last = self
+            .ranges
+            .partition_point(|r| r.start() <= range.end().saturating_add(1));
+        let merged = self.ranges[first..last]
+            .iter()
+            .fold(range, |merged, r| merged.union(r).expect("touching ranges"));
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// Number of sections.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(Range::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains_section(&self, section: u32) -> bool {
+        let i = self.ranges.partition_point(|r| r.end() < section);
+        self.ranges
+            .get(i)
+            .is_some_and(|r| r.contains_section(section))
+    }
+
+    /// Whether every section of `range` is in the set.
+    pub fn contains(&self, range: &Range) -> bool {
+        let i = self.ranges.partition_point(|r| r.end() < range.start());
+        self.ranges.get(i).is_some_and(|r| r.contains(range))
+    }
+
+    /// The smallest range holding every section of the set.
+    pub fn hull(&self) -> Option<Range> {
+        let (first, last) = (self.ranges.first()?, self.ranges.last()?);
+        Range::new(first.start(), last.end())
+    }
+
+    /// Sections of `within` which are not in the set.
+    pub fn complement(&self, within: Range) -> IntervalSet {
+        let mut gaps = IntervalSet::new();
+        // First section of `within` not known to be in the set or a gap yet.
+        let mut next = within.start() as u64;
+        for r in &self.ranges {
+            if r.end() < within.start() || r.start() > within.end() {
+                continue;
+            }
+            if r.start() as u64 > next {
+                gaps.ranges
+                    .push(Range::new(next as u32, r.start() - 1).expect("gap after next"));
+            }
+            next = next.max(r.end() as u64 + 1);
+        }
+        if next <= within.end() as u64 {
+            gaps.ranges
+                .push(Range::new(next as u32, within.end()).expect("gap up to the end"));
+        }
+        gaps
+    }
+
+    /// The set without the sections of `range`.
+    pub fn difference(&self, range: &Range) -> IntervalSet {
+        IntervalSet {
+            ranges: self
+                .ranges
+                .iter()
+                .flat_map(|r| r.difference(range))
+                .collect(),
+        }
+    }
+}
+
+impl FromIterator<Range> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range>>(iter: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Ranges separated by commas, `2-4,6-8`, or `-` for an empty set.
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ranges.is_empty() {
+            return write!(f, "-");
+        }
+        for (i, r) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", r)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sections found in more than `k` of `ranges`.
+pub fn deeper_than(ranges: impl IntoIterator<Item = Range>, k: usize) -> IntervalSet {
+    // +1 where a range starts, -1 after it ends.
+    let mut events: Vec<(u64, i64)> = ranges
+        .into_iter()
+        .flat_map(|r| [(r.start() as u64, 1), (r.end() as u64 + 1, -1)])
+        .collect();
+    events.sort_unstable();
+    let mut deep = IntervalSet::new();
+    let mut depth = 0;
+    let mut since = 0;
+    for (at, change) in events {
+        let was_deep = depth > k as i64;
+        depth += change;
+        let is_deep = depth > k as i64;
+        if !was_deep && is_deep {
+            since = at;
+        } else if was_deep && !is_deep && since < at {
+            deep.insert(Range::new(since as u32, (at - 1) as u32).expect("ascending events"));
+        }
+    }
+    deep
+}
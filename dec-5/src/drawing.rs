@@ -0,0 +1,52 @@
+use std::fmt::Write;
+
+use crate::Stacks;
+
+/// The stacks drawn as in the puzzle, crates in brackets above the stack
+/// numbers:
+///
+/// ```text
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///  1   2   3
+/// ```
+///
+/// Columns are as wide as the longest label, so the drawing reads back the
+/// same stacks whatever their labels.
+pub fn draw(stacks: &Stacks) -> String {
+    let label = stacks
+        .iter()
+        .flatten()
+        .map(|c| c.chars().count())
+        .max()
+        .unwrap_or(1);
+    let width = label + 2;
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for level in (0..height).rev() {
+        let mut row = String::new();
+        for (i, stack) in stacks.iter().enumerate() {
+            if i > 0 {
+                row.push(' ');
+            }
+            match stack.get(level) {
+                Some(c) => {
+                    let _ = write!(row, "{:<width$}", format!("[{}]", c), width = width);
+                }
+                None => row.push_str(&" ".repeat(width)),
+            }
+        }
+        let _ = writeln!(out, "{}", row.trim_end());
+    }
+    let mut footer = String::new();
+    for i in 0..stacks.len() {
+        let number = (i + 1).to_string();
+        let left = width.saturating_sub(number.len()) / 2;
+        let cell = format!("{}{}", " ".repeat(left), number);
+        let _ = write!(footer, "{:<width$}", cell, width = width + 1);
+    }
+    let _ = writeln!(out, "{}", footer.trim_end());
+    out
+}
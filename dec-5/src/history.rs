@@ -0,0 +1,60 @@
+//! Stacks changed one step at a time, every step kept so that it can be
+//! undone, then redone.
+
+use crate::{CraneModel, Illegal, Move, Stacks};
+
+pub struct History {
+    crane: CraneModel,
+    stacks: Stacks,
+    done: Vec<Move>,
+    undone: Vec<Move>,
+}
+
+impl History {
+    pub fn new(stacks: Stacks, crane: CraneModel) -> History {
+        History {
+            crane,
+            stacks,
+            done: vec![],
+            undone: vec![],
+        }
+    }
+
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    /// Steps carried out and not undone, from the first.
+    pub fn done(&self) -> &[Move] {
+        &self.done
+    }
+
+    /// Carries out step `m`, forgetting the steps undone until now. An
+    /// illegal step changes nothing.
+    pub fn apply(&mut self, m: Move) -> Result<(), Illegal> {
+        self.crane.apply(&mut self.stacks, &m)?;
+        self.done.push(m);
+        self.undone.clear();
+        Ok(())
+    }
+
+    /// Undoes the last step carried out, `None` when there is none.
+    pub fn undo(&mut self) -> Option<Move> {
+        let m = self.done.pop()?;
+        self.crane
+            .apply(&mut self.stacks, &m.inverse())
+            .expect("the inverse of a step carried out");
+        self.undone.push(m);
+        Some(m)
+    }
+
+    /// Carries out again the last step undone, `None` when there is none.
+    pub fn redo(&mut self) -> Option<Move> {
+        let m = self.undone.pop()?;
+        self.crane
+            .apply(&mut self.stacks, &m)
+            .expect("a step carried out before");
+        self.done.push(m);
+        Some(m)
+    }
+}
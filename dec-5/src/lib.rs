@@ -0,0 +1,347 @@
+/*
+--- Day 5: Supply Stacks ---
+
+The expedition can depart as soon as the final supplies have been unloaded from the ships.
+Supplies are stored in stacks of marked crates, but because the needed supplies are buried under many other crates,
+the crates need to be rearranged.
+
+The ship has a giant cargo crane capable of moving crates between stacks.
+To ensure none of the crates get crushed or fall over,
+the crane operator will rearrange them in a series of carefully-planned steps.
+ After the crates are rearranged, the desired crates will be at the top of each stack.
+
+The Elves don't want to interrupt the crane operator during this delicate procedure, b
+ut they forgot to ask her which crate will end up where, and they want to be ready to unload them as soon as
+possible so they can embark.
+
+They do, however,
+have a drawing of the starting stacks of crates and the rearrangement procedure (your puzzle input). For example:
+
+    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+
+In this example, there are three stacks of crates.
+Stack 1 contains two crates: crate Z is on the bottom, and crate N is on top.
+Stack 2 contains three crates; from bottom to top, they are crates M, C, and D.
+Finally, stack 3 contains a single crate, P.
+
+Then, the rearrangement procedure is given.
+In each step of the procedure, a quantity of crates is moved from one stack to a different stack.
+In the first step of the above rearrangement procedure, one crate is moved from stack 2 to stack 1,
+resulting in this configuration:
+
+[D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+In the second step, three crates are moved from stack 1 to stack 3.
+Crates are moved one at a time, so the first crate to be moved (D) ends up below the second and third crates:
+
+        [Z]
+        [N]
+    [C] [D]
+    [M] [P]
+ 1   2   3
+
+Then, both crates are moved from stack 2 to stack 1.
+Again, because crates are moved one at a time, crate C ends up below crate M:
+
+        [Z]
+        [N]
+[M]     [D]
+[C]     [P]
+ 1   2   3
+
+Finally, one crate is moved from stack 1 to stack 2:
+
+        [Z]
+        [N]
+        [D]
+[C] [M] [P]
+ 1   2   3
+
+The Elves just need to know which crate will end up on top of each stack;
+in this example, the top crates are C in stack 1, M in stack 2, and Z in stack 3,
+so you should combine these together and give the Elves the message CMZ.
+
+After the rearrangement procedure completes, what crate ends up on top of each stack?
+*/
+
+use std::fmt;
+
+use common::{Answer, Error, Line, Solution};
+use log::{debug, trace};
+
+pub mod drawing;
+pub mod history;
+pub mod replay;
+
+/// Crates of every stack, from the bottom one to the top one.
+pub type Stacks = Vec<Vec<String>>;
+
+/// A step of the procedure, with stacks counted from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+    /// Line of the step in the input.
+    pub line: usize,
+}
+
+/// Written as in the procedure, `move 1 from 2 to 1`.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from + 1, self.to + 1)
+    }
+}
+
+impl Move {
+    /// The step putting the crates back where they were taken from. It undoes
+    /// this step for both crane models: the CrateMover 9000 reverses the crates
+    /// again.
+    pub fn inverse(&self) -> Move {
+        Move { from: self.to, to: self.from, ..*self }
+    }
+}
+
+/// Why a step cannot be carried out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Illegal {
+    /// Stack `stack`, counted from 0, is not one of the `stacks` of the drawing.
+    NoSuchStack { stack: usize, stacks: usize },
+    /// Crates are taken from the stack they are put on.
+    SameStack,
+    /// The step takes `wanted` crates from a stack of `held`.
+    NotEnoughCrates { wanted: usize, held: usize },
+}
+
+impl fmt::Display for Illegal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Illegal::NoSuchStack { stack, stacks } => {
+                write!(f, "there is no stack {}, only {}", stack + 1, stacks)
+            }
+            Illegal::SameStack => write!(f, "the crates are put back on their own stack"),
+            Illegal::NotEnoughCrates { wanted, held } => {
+                write!(f, "{} crates are taken from a stack of {}", wanted, held)
+            }
+        }
+    }
+}
+
+/// The first illegal step of a procedure, the `step`-th counted from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IllegalMove {
+    pub step: usize,
+    pub m: Move,
+    pub reason: Illegal,
+}
+
+/// `line: step: reason`, to be prefixed with the file name.
+impl fmt::Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.m.line, self.m, self.reason)
+    }
+}
+
+/// The starting stacks and the rearrangement procedure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+}
+
+// The drawing ends with the stack numbers, each one under the crates of its
+// stack: `[Z] [M] [P]` over ` 1   2   3`. A crate belongs to the stack whose
+// number is within its brackets, so labels may be longer than a letter.
+fn parse_drawing(drawing: &[Line]) -> Result<Stacks, Error> {
+    let Some((footer, rows)) = drawing.split_last() else {
+        return Err(Error::end_of_input(1, "a drawing of the stacks"));
+    };
+    let mut columns = vec![];
+    for word in footer.text.split_whitespace() {
+        let n = footer.parse::<usize>(word, "a stack number")?;
+        if n != columns.len() + 1 {
+            return Err(footer.error(word, format!("stack number {}", columns.len() + 1)));
+        }
+        columns.push(footer.column(word) - 1);
+    }
+    if columns.is_empty() {
+        return Err(footer.error(footer.text, "the stack numbers"));
+    }
+
+    let mut stacks: Stacks = vec![vec![]; columns.len()];
+    for (level, row) in rows.iter().rev().enumerate() {
+        let mut rest = row.text.trim_start();
+        while !rest.is_empty() {
+            let label = row.strip_prefix(rest, "[")?;
+            let (label, after) = row.split_once(label, "]")?;
+            if label.is_empty() {
+                return Err(row.error(&rest[1..], "a crate label"));
+            }
+            let (open, close) = (row.column(rest) - 1, row.column(after) - 2);
+            let stack = columns
+                .iter()
+                .position(|&c| open <= c && c <= close)
+                .ok_or_else(|| row.error(rest, "a crate above a stack number"))?;
+            if stacks[stack].len() != level {
+                return Err(row.error(rest, "a crate resting on another one"));
+            }
+            stacks[stack].push(label.to_string());
+            rest = after.trim_start();
+        }
+    }
+    Ok(stacks)
+}
+
+// The drawing, an empty line, then one step of the procedure per line. Every
+// step must be legal.
+fn parse(input: &str) -> Result<Plan, Error> {
+    let lines: Vec<Line> = common::lines(input).collect();
+    let Some(blank) = lines.iter().position(|l| l.text.trim().is_empty()) else {
+        return Err(Error::end_of_input(lines.len() + 1, "an empty line after the drawing"));
+    };
+    let stacks = parse_drawing(&lines[..blank])?;
+    let steps = lines[blank + 1..].iter().map(parse_move).collect::<Result<Vec<_>, Error>>()?;
+    let plan = Plan { stacks, moves: steps.iter().map(|(m, _)| *m).collect() };
+    if let Err(illegal) = validate(&plan) {
+        let (l, [qnt, from, to]) = (&lines[blank + 1 + illegal.step], steps[illegal.step].1);
+        return Err(match illegal.reason {
+            Illegal::NoSuchStack { stack, stacks } => {
+                let part = if stack == illegal.m.from { from } else { to };
+                l.error(part, format!("a stack from 1 to {}", stacks))
+            }
+            Illegal::SameStack => l.error(to, format!("a stack other than {}", illegal.m.from + 1)),
+            Illegal::NotEnoughCrates { held, .. } => {
+                l.error(qnt, format!("at most {} crates", held))
+            }
+        });
+    }
+    Ok(plan)
+}
+
+// `move <quantity> from <stack> to <stack>`, with the text of the three numbers.
+fn parse_move<'a>(l: &Line<'a>) -> Result<(Move, [&'a str; 3]), Error> {
+    let rest = l.strip_prefix(l.text, "move ")?;
+    let (qnt, rest) = l.split_once(rest, " from ")?;
+    let (from, to) = l.split_once(rest, " to ")?;
+    let count = l.parse::<usize>(qnt, "a quantity")?;
+    let m = Move { count, from: stack_index(l, from)?, to: stack_index(l, to)?, line: l.number };
+    Ok((m, [qnt, from, to]))
+}
+
+// Stacks are numbered from 1 in the procedure.
+fn stack_index(l: &Line, part: &str) -> Result<usize, Error> {
+    match l.parse::<usize>(part, "a stack number")? {
+        0 => Err(l.error(part, "a stack number")),
+        n => Ok(n - 1),
+    }
+}
+
+/// The crates on top of the stacks, empty stacks left out.
+pub fn message(stacks: &Stacks) -> String {
+    stacks.iter().filter_map(|s| s.last()).map(String::as_str).collect()
+}
+
+/// The crane doing the rearrangement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CraneModel {
+    /// Moves crates one at a time, so the moved crates end up in reverse
+    /// order, as in part one.
+    CrateMover9000,
+    /// Moves all the crates of a step at once, keeping their order, as in
+    /// part two.
+    CrateMover9001,
+}
+
+impl CraneModel {
+    /// Carries out step `m` on `stacks`, left untouched when the step is
+    /// illegal.
+    pub fn apply(self, stacks: &mut Stacks, m: &Move) -> Result<(), Illegal> {
+        check(stacks, m)?;
+        let (i, j) = (m.from, m.to);
+        debug!("move {} from {} to {}", m.count, i + 1, j + 1);
+        trace!("before: from {:?}  to{:?}", stacks[i], stacks[j]);
+        let at = stacks[i].len() - m.count;
+        let mut moved = stacks[i].split_off(at);
+        if self == CraneModel::CrateMover9000 {
+            moved.reverse();
+        }
+        stacks[j].append(&mut moved);
+        trace!("after: from {:?} to{:?}", stacks[i], stacks[j]);
+        Ok(())
+    }
+}
+
+/// Whether step `m` can be carried out on `stacks`, with either crane.
+pub fn check(stacks: &Stacks, m: &Move) -> Result<(), Illegal> {
+    for stack in [m.from, m.to] {
+        if stack >= stacks.len() {
+            return Err(Illegal::NoSuchStack { stack, stacks: stacks.len() });
+        }
+    }
+    if m.from == m.to {
+        return Err(Illegal::SameStack);
+    }
+    let held = stacks[m.from].len();
+    if m.count > held {
+        return Err(Illegal::NotEnoughCrates { wanted: m.count, held });
+    }
+    Ok(())
+}
+
+/// The stacks once `crane` has carried out the whole procedure, or the first
+/// step it cannot carry out.
+pub fn rearrange(plan: &Plan, crane: CraneModel) -> Result<Stacks, IllegalMove> {
+    let mut stacks = plan.stacks.clone();
+    for (step, m) in plan.moves.iter().enumerate() {
+        crane
+            .apply(&mut stacks, m)
+            .map_err(|reason| IllegalMove { step, m: *m, reason })?;
+    }
+    Ok(stacks)
+}
+
+/// Checks every step of the procedure from the starting stacks, stopping at
+/// the first illegal one. Both crane models agree on it, since they move as
+/// many crates.
+pub fn validate(plan: &Plan) -> Result<(), IllegalMove> {
+    rearrange(plan, CraneModel::CrateMover9001).map(|_| ())
+}
+
+/// The procedure bringing the stacks back to where `moves` started them, for
+/// both crane models: every step undone, from the last. Each inverse step
+/// keeps the line of the step it undoes.
+pub fn inverse(moves: &[Move]) -> Vec<Move> {
+    moves.iter().rev().map(Move::inverse).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    // Starting stacks and steps of the procedure.
+    type Input = Plan;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(plan: &Self::Input) -> Answer {
+        let stacks = rearrange(plan, CraneModel::CrateMover9000).expect("a validated procedure");
+        message(&stacks).into()
+    }
+
+    fn part2(plan: &Self::Input) -> Answer {
+        let stacks = rearrange(plan, CraneModel::CrateMover9001).expect("a validated procedure");
+        message(&stacks).into()
+    }
+}
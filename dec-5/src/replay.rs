@@ -0,0 +1,41 @@
+use crate::history::History;
+use crate::{CraneModel, Move, Plan, Stacks};
+
+/// Carries out a procedure one step at a time.
+pub struct Replay<'a> {
+    plan: &'a Plan,
+    history: History,
+}
+
+impl<'a> Replay<'a> {
+    /// Starts from the stacks of the drawing.
+    pub fn new(plan: &'a Plan, crane: CraneModel) -> Replay<'a> {
+        Replay {
+            plan,
+            history: History::new(plan.stacks.clone(), crane),
+        }
+    }
+
+    pub fn stacks(&self) -> &Stacks {
+        self.history.stacks()
+    }
+
+    /// Number of steps carried out.
+    pub fn done(&self) -> usize {
+        self.history.done().len()
+    }
+
+    /// Carries out the next step, `None` once the procedure is over or stuck
+    /// on an illegal step, which `Day5::parse` never lets through.
+    pub fn step(&mut self) -> Option<&'a Move> {
+        let m = self.plan.moves.get(self.done())?;
+        self.history.apply(*m).ok()?;
+        Some(m)
+    }
+
+    /// Goes on, or back, until `n` steps are done or the procedure is over.
+    pub fn jump(&mut self, n: usize) {
+        while self.done() > n && self.history.undo().is_some() {}
+        while self.done() < n && self.step().is_some() {}
+    }
+}
@@ -45,67 +45,48 @@ How many characters need to be processed before the first start-of-packet marker
 
 */
 
-use std::fs;
-use queues::*;
-// use std::io::prelude::*;
-use std::collections::VecDeque;
-
-fn unique(s: &str) -> Option<(usize, usize, char)> {
-    s.chars().enumerate().find_map(|(i, c)| {
-        s.chars()
-            .enumerate()
-            .skip(i + 1)
-            .find(|(_, other)| c == *other)
-            .map(|(j, _)| (i, j, c))
-    })
-}
-fn part_one() -> std::io::Result<()> {
-
-    let data  = fs::read_to_string("input.txt").expect("Unable to read file");
-    let list = data.chars().into_iter();
-
-    // let mut start: Vec<char> = list.take(3).collect();
-    let mut q: VecDeque<char> = VecDeque::new();
-
-
-    for (idx, new_char) in list.enumerate() {
-        if idx < 14 {
-            q.push_back(new_char);
+use common::{Answer, Error, Line, Solution};
+use log::info;
+
+/// Size of a start-of-packet marker.
+pub const START_OF_PACKET: usize = 4;
+/// Size of a start-of-message marker.
+pub const START_OF_MESSAGE: usize = 14;
+
+/// Number of characters processed once the last `window` ones are all
+/// different, `None` if they never are. The stream is ASCII, one byte per
+/// character.
+pub fn find_marker(stream: &str, window: usize) -> Option<usize> {
+    if window == 0 {
+        return Some(0);
+    }
+    let bytes = stream.as_bytes();
+    // Occurrences of every character in the window, and how many characters
+    // occur more than once.
+    let mut counts = [0usize; 256];
+    let mut repeated = 0;
+    for (i, &c) in bytes.iter().enumerate() {
+        counts[c as usize] += 1;
+        if counts[c as usize] == 2 {
+            repeated += 1;
         }
-        else {
-
-            match q.iter().enumerate().find_map(|(i, c)| {
-                q.iter()
-                    .enumerate()
-                    .skip(i + 1)
-                    .find(|(_, other)| {
-                        if c == *other {
-                        }
-                        c == *other
-                    })
-                    .map(|(j, _)| {
-                        (i, j, c)
-                    })
-            }) {
-                None => {
-                    println!("Is unique at index {}", idx);
-                    break
-
-                },
-                Some((i, j, c)) => ()
-                // println!(
-                //     " is not unique\n\tfirst duplicate: \"{}\" at indices {}",
-                //     c, idx+1
-                // ),
+        if i >= window {
+            let out = bytes[i - window] as usize;
+            counts[out] -= 1;
+            if counts[out] == 1 {
+                repeated -= 1;
             }
-            q.pop_front();
-            q.push_back(new_char);
-
-
         }
-
+        if i + 1 >= window && repeated == 0 {
+            info!("marker of {} characters after character {}", window, i + 1);
+            return Some(i + 1);
+        }
     }
-    Ok(())
+    None
+}
+
+fn part_one(data: &str) -> Option<usize> {
+    find_marker(data, START_OF_PACKET)
 }
 
 /*
@@ -124,10 +105,38 @@ Here are the first positions of start-of-message markers for all of the above ex
 How many characters need to be processed before the first start-of-message marker is detected?
 
  */
-fn part_two() -> std::io::Result<()> {
-    Ok(())
+
+fn part_two(data: &str) -> Option<usize> {
+    find_marker(data, START_OF_MESSAGE)
+}
+
+// Markers are found byte by byte.
+fn check_ascii(line: &Line) -> Result<(), Error> {
+    match line.text.char_indices().find(|(_, c)| !c.is_ascii()) {
+        Some((i, c)) => Err(line.error(&line.text[i..i + c.len_utf8()], "an ASCII character")),
+        None => Ok(()),
+    }
 }
-fn main() {
-    part_one();
-    part_two();
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    // The datastream buffer, on a single line of ASCII characters.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let Some(line) = common::lines(input).next() else {
+            return Ok(String::new());
+        };
+        check_ascii(&line)?;
+        Ok(line.text.trim_end().to_string())
+    }
+
+    fn part1(data: &Self::Input) -> Answer {
+        part_one(data).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(data: &Self::Input) -> Answer {
+        part_two(data).map_or(Answer::Unsolved, Answer::from)
+    }
 }
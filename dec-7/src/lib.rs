@@ -91,17 +91,16 @@ Find all of the directories with a total size of at most 100000. What is the sum
 */
 
 
-use std::fs::File;
-use std::io::BufReader;
-use std::io::prelude::*;
 use regex::Regex;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::collections::HashMap;
+use common::{Answer, Error, Solution};
+use log::{debug, info, trace};
 #[derive(PartialEq)]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 
-struct Node {
+pub struct Node {
   value: Option<i32>,
   children: HashMap<String, Rc<RefCell<Node>>>,
   parent: Option<Rc<RefCell<Node>>>,
@@ -109,61 +108,61 @@ struct Node {
 
 impl Node {
   pub fn new() -> Node {
-    return Node {
+    Node {
       value: None,
       children: HashMap::new(),
       parent: None,
-    };
+    }
   }
 
   pub fn find_bigger(&self, limit: i32, acc: &mut Vec<i32>) -> i32 {
     let iter = &mut self.children.iter();
     let mut sum = 0;
     for (k, c) in iter {
-      let mut tmp_sum  = 0;
-      if let Some(value) = c.borrow().value {
-        tmp_sum = value;
+      let tmp_sum = if let Some(value) = c.borrow().value {
+        value
       }
       else {
-        tmp_sum =  c.borrow().find_bigger(limit, acc);
+        let tmp_sum = c.borrow().find_bigger(limit, acc);
         if tmp_sum >= limit {
           acc.push(tmp_sum);
-          println!("BIGGER  tmp {} Sum {}",k, tmp_sum);
+          debug!("BIGGER  tmp {} Sum {}",k, tmp_sum);
         }
-      }
+        tmp_sum
+      };
       sum += tmp_sum;
 
     }
 
-    return sum
+    sum
   }
 
   pub fn reduce(&self, limit: i32, acc: &mut Vec<i32>) -> i32 {
     let iter = &mut self.children.iter();
     let mut sum = 0;
     for (k, c) in iter {
-      let mut tmp_sum  = 0;
-      if let Some(value) = c.borrow().value {
+      let tmp_sum = if let Some(value) = c.borrow().value {
         // println!("SOme value {} {}", k, value);
-        tmp_sum = value;
+        value
       }
       else {
         // println!("NO value {}", k);
-        tmp_sum =  c.borrow().reduce(limit, acc);
+        let tmp_sum = c.borrow().reduce(limit, acc);
         if tmp_sum < limit {
           acc.push(tmp_sum);
-          println!("{} Sum {}",k, tmp_sum);
+          debug!("{} Sum {}",k, tmp_sum);
         }
-      }
+        tmp_sum
+      };
       sum += tmp_sum;
     }
-    return sum
+    sum
   }
   pub fn print(&self) -> String {
     if let Some(value) = self.value {
-      return value.to_string();
+      value.to_string()
     } else {
-      return String::from("[")
+      String::from("[")
         + &self
           .children
           .iter()
@@ -173,48 +172,48 @@ impl Node {
           })
           .collect::<Vec<String>>()
           .join(",")
-        + "]";
+        + "]"
     }
   }
 }
-fn part_one() -> std::io::Result<()> {
-  let file = File::open("input.txt")?;
-
+fn parse(input: &str) -> Result<Rc<RefCell<Node>>, Error> {
   let cd_regex = Regex::new(r"\$ cd (.*)?").unwrap();
   let dir_regex = Regex::new(r"dir (.*)?").unwrap();
   let file_regex = Regex::new(r"([0-9]+) (.*)?").unwrap();
 
   let root = Rc::new(RefCell::new(Node::new()));
   let mut current = Rc::clone(&root);
-  let reader = BufReader::new(file);
-  for line in reader.lines(){
+  for line in common::lines(input) {
+    let l = line.text;
     // println!("--- current state: {}", current.borrow().print());
     // println!("--- root state: {}", root.borrow().print());
-    let l = line?;
 
-    if cd_regex.is_match(&l){
-      let matches = cd_regex.captures(&l).unwrap();
-      let dir = matches.get(1).unwrap().as_str().to_string();
+    if cd_regex.is_match(l){
+      let matches = cd_regex.captures(l).unwrap();
+      let dir_part = matches.get(1).unwrap().as_str();
+      let dir = dir_part.to_string();
       if dir == "/" {
-        println!("AT ROOT");
+        trace!("AT ROOT");
         continue
       }
       else if dir == ".." {
-        println!("cd ..");
+        trace!("cd ..");
         let current_clone = Rc::clone(&current);
-        current = Rc::clone(current_clone.borrow_mut().parent.as_ref().unwrap());
+        let parent = current_clone.borrow().parent.clone();
+        current = parent.ok_or_else(|| line.error(dir_part, "a directory below the root"))?;
 
       }
       else {
-        println!("cd {}", dir);
+        trace!("cd {}", dir);
         let current_clone = Rc::clone(&current);
-        current = Rc::clone(current_clone.borrow().children.get(&dir).unwrap());
+        let child = current_clone.borrow().children.get(&dir).cloned();
+        current = child.ok_or_else(|| line.error(dir_part, "a listed directory"))?;
       }
     }
-    else if dir_regex.is_match(&l) {
-        let matches = dir_regex.captures(&l).unwrap();
+    else if dir_regex.is_match(l) {
+        let matches = dir_regex.captures(l).unwrap();
         let dir = matches.get(1).unwrap().as_str().to_string();
-        println!("DIR {}", dir);
+        trace!("DIR {}", dir);
 
         let child = Rc::new(RefCell::new(Node::new()));
         current.borrow_mut().children.insert(dir, Rc::clone(&child));
@@ -224,11 +223,11 @@ fn part_one() -> std::io::Result<()> {
           // mut_child.value = Some(0);
         }
     }
-    else if file_regex.is_match(&l) {
-      let matches = file_regex.captures(&l).unwrap();
-      let fsz = matches.get(1).unwrap().as_str().parse::<i32>().unwrap();
+    else if file_regex.is_match(l) {
+      let matches = file_regex.captures(l).unwrap();
+      let fsz = line.parse::<i32>(matches.get(1).unwrap().as_str(), "a file size")?;
       let filename = matches.get(2).unwrap().as_str().to_string();
-      println!("FILE {} {}", filename, fsz);
+      trace!("FILE {} {}", filename, fsz);
 
       let child = Rc::new(RefCell::new(Node::new()));
       current.borrow_mut().children.insert(filename, Rc::clone(&child));
@@ -239,21 +238,20 @@ fn part_one() -> std::io::Result<()> {
       }
     }
     else if l == "$ ls" {
-      println!("LS");
+      trace!("LS");
+    }
+    else {
+      return Err(line.error(l, "a command, a directory or a file"));
     }
   }
-  let mut small_sum_acc:Vec<i32> = vec![];
-  println!("ROOT > {:?}", root.borrow().print());
-  let total_sum = root.borrow().reduce(100000,&mut small_sum_acc);
-  println!("{}", small_sum_acc.iter().sum::<i32>());
+  Ok(root)
+}
 
-  let free_space = 70000000 - total_sum;
-  let target = 30000000 - free_space;
-  println!("free_space {} target {}  total_sum {}",free_space, target, total_sum);
-  let mut large_folder_acc:Vec<i32> = vec![];
-  root.borrow().find_bigger(target, &mut large_folder_acc);
-  println!("small large {:?}", large_folder_acc.iter().min());
-  Ok(())
+fn part_one(root: &Rc<RefCell<Node>>) -> i32 {
+  let mut small_sum_acc:Vec<i32> = vec![];
+  debug!("ROOT > {:?}", root.borrow().print());
+  root.borrow().reduce(100000,&mut small_sum_acc);
+  small_sum_acc.iter().sum::<i32>()
 }
 
 
@@ -289,10 +287,31 @@ What is the total size of that directory?
 
 */
 
-fn part_two() -> std::io::Result<()> {
-  Ok(())
+fn part_two(root: &Rc<RefCell<Node>>) -> Option<i32> {
+  let total_sum = root.borrow().reduce(100000, &mut vec![]);
+  let free_space = 70000000 - total_sum;
+  let target = 30000000 - free_space;
+  info!("free_space {} target {}  total_sum {}",free_space, target, total_sum);
+  let mut large_folder_acc:Vec<i32> = vec![];
+  root.borrow().find_bigger(target, &mut large_folder_acc);
+  large_folder_acc.iter().min().copied()
 }
-fn main() {
-  part_one();
-  part_two();
+
+pub struct Day7;
+
+impl Solution for Day7 {
+  // Root directory of the filesystem.
+  type Input = Rc<RefCell<Node>>;
+
+  fn parse(input: &str) -> Result<Self::Input, Error> {
+    parse(input)
+  }
+
+  fn part1(root: &Self::Input) -> Answer {
+    part_one(root).into()
+  }
+
+  fn part2(root: &Self::Input) -> Answer {
+    part_two(root).map_or(Answer::Unsolved, Answer::from)
+  }
 }
@@ -264,56 +264,34 @@ Simulate your complete hypothetical series of motions. How many positions does t
 
 */
 
-use std::fs::File;
-use std::io::BufReader;
-use std::io::prelude::*;
 use std::collections::HashMap;
-use regex::Regex;
+use common::{Answer, Error, Solution};
+use log::{debug, trace};
 
 fn move_tail(head: &(i32, i32), tail: &mut (i32, i32)) {
     let dx = head.0 - tail.0;
     let dy = head.1 - tail.1;
-    println!("dx {} dy {} h: {:?} t: {:?}", dx, dy, head, tail);
+    trace!("dx {} dy {} h: {:?} t: {:?}", dx, dy, head, tail);
 
-    // NNE
-    if dx == 1 && dy == 2 {
+    // NNE, NEE
+    if (dx == 1 && dy == 2) || (dx == 2 && dy == 1) {
         tail.0 +=1;
         tail.1 += 1;
     }
-    // NEE
-    else if dx == 2 && dy == 1 {
-        tail.0 +=1;
-        tail.1 += 1;
-    }
-    // NNW
-    else if dx == -1 && dy == 2 {
-        tail.0 -=1;
-        tail.1 += 1;
-    }
-    // NWW
-    else if dx == -2 && dy == 1 {
+    // NNW, NWW
+    else if (dx == -1 && dy == 2) || (dx == -2 && dy == 1) {
         tail.0 -=1;
         tail.1 += 1;
     }
 
-    // SSE
-    else if dx == 1 && dy == -2 {
-        tail.0 +=1;
-        tail.1 -= 1;
-    }
-    // SEE
-    else if dx == 2 && dy == -1 {
+    // SSE, SEE
+    else if (dx == 1 && dy == -2) || (dx == 2 && dy == -1) {
         tail.0 +=1;
         tail.1 -= 1;
     }
 
-    // SSW
-    else if dx == -1 && dy == -2 {
-        tail.0 -=1;
-        tail.1 -= 1;
-    }
-    // SWW
-    else if dx == -2 && dy == -1 {
+    // SSW, SWW
+    else if (dx == -1 && dy == -2) || (dx == -2 && dy == -1) {
         tail.0 -=1;
         tail.1 -= 1;
     }
@@ -355,16 +333,16 @@ fn move_tail(head: &(i32, i32), tail: &mut (i32, i32)) {
         tail.1 +=1;
     }
 
-    println!("new tail pos {:?}\n", tail);
+    trace!("new tail pos {:?}", tail);
 }
 
 fn move_head(direction: &str, nb_steps: i32, knots: &mut Vec<(i32, i32)>, hashmap: &mut HashMap<(i32, i32), bool>)  {
-    println!("-----\nDirection {} {} {:?}", direction, nb_steps, knots);
+    debug!("Direction {} {} {:?}", direction, nb_steps, knots);
 
-    let mut head = knots.first().unwrap().clone();
+    let mut head = *knots.first().unwrap();
     let mut tails = Vec::from_iter(knots[1..].iter().cloned());
     let tails_len = tails.len();
-    for i in 0..nb_steps {
+    for _i in 0..nb_steps {
         match direction {
             "R" => {
                 head.0 +=1;
@@ -381,18 +359,15 @@ fn move_head(direction: &str, nb_steps: i32, knots: &mut Vec<(i32, i32)>, hashma
             _ => ()
         }
         // println!(">>> head movement {} #{}/{:?} >>> head {:?}",direction, i+1, nb_steps, head);
-        let mut current_head = head.clone();
+        let mut current_head = head;
 
-        let mut j = 0;
-
-        for mut tail in &mut tails {
+        for (j, tail) in tails.iter_mut().enumerate() {
             // println!("tail #{:?} head {:?} tail {:?}", j, current_head, tail);
-            move_tail(&current_head, &mut tail);
+            move_tail(&current_head, tail);
             if j == tails_len -1 {
                 hashmap.insert(*tail, true);
             }
             current_head = *tail;
-            j+=1;
         }
     }
 
@@ -401,23 +376,29 @@ fn move_head(direction: &str, nb_steps: i32, knots: &mut Vec<(i32, i32)>, hashma
         knots[i+1] = tail;
     }
 }
-fn part_one() ->  std::io::Result<()> {
-    let regex = Regex::new(r"([A-Z]?) ([0-9]+)").unwrap();
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
+// Direction and number of steps of each motion of the head.
+fn parse(input: &str) -> Result<Vec<(String, i32)>, Error> {
+    common::lines(input)
+        .map(|l| {
+            let (direction, nb_steps) = l.split_once(l.text, " ")?;
+            if !["R", "L", "U", "D"].contains(&direction) {
+                return Err(l.error(direction, "R, L, U or D"));
+            }
+            let nb_steps = l.parse::<i32>(nb_steps, "a number of steps")?;
+            Ok((direction.to_string(), nb_steps))
+        })
+        .collect()
+}
+
+fn part_one(motions: &[(String, i32)]) -> usize {
     let mut hashmap: HashMap<(i32, i32), bool> = HashMap::new();
     let mut knots = vec![(0,0); 2];
     hashmap.insert(knots[1], true);
-    for line in reader.lines() {
-        let l = line?;
-        let matches = regex.captures(&l).unwrap();
-        let direction = matches.get(1).unwrap().as_str().to_string();
-        let nb_steps = matches.get(2).unwrap().as_str().parse::<i32>().unwrap();
-        move_head(&direction, nb_steps, &mut knots, &mut hashmap);
+    for (direction, nb_steps) in motions {
+        move_head(direction, *nb_steps, &mut knots, &mut hashmap);
     }
     // println!("hashmap {:?}", hashmap);
-    println!("count {}", hashmap.keys().len());
-    Ok(())
+    hashmap.keys().len()
 }
 
 /*
@@ -863,30 +844,34 @@ Now, the tail (9) visits 36 positions (including s) at least once:
 Simulate your complete series of motions on a larger rope with ten knots. How many positions does the tail of the rope visit at least once?
 
 */
-fn part_two() ->  std::io::Result<()> {
-    let regex = Regex::new(r"([A-Z]?) ([0-9]+)").unwrap();
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
+fn part_two(motions: &[(String, i32)]) -> usize {
     let mut hashmap: HashMap<(i32, i32), bool> = HashMap::new();
     let mut knots = vec![(0,0); 10];
     // println!("knots {:?}", knots);
     hashmap.insert(knots[9], true);
-    for line in reader.lines() {
-        let l = line?;
-        let matches = regex.captures(&l).unwrap();
-        let direction = matches.get(1).unwrap().as_str().to_string();
-        let nb_steps = matches.get(2).unwrap().as_str().parse::<i32>().unwrap();
-        move_head(&direction, nb_steps, &mut knots, &mut hashmap);
+    for (direction, nb_steps) in motions {
+        move_head(direction, *nb_steps, &mut knots, &mut hashmap);
         // println!("knots {:?}", knots);
         // println!("hashmap {:?}", hashmap);
     }
     // println!("hashmap {:?}", hashmap);
-    println!("count {}", hashmap.keys().len());
-
-   Ok(())
+    hashmap.keys().len()
 }
 
-fn main() {
-//    part_one();
-   part_two();
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(String, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(motions: &Self::Input) -> Answer {
+        part_one(motions).into()
+    }
+
+    fn part2(motions: &Self::Input) -> Answer {
+        part_two(motions).into()
+    }
 }